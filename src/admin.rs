@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Admin rpc interface.
+
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::admin::{NodeInfo, PeerInfo};
+
+/// Admin rpc interface.
+#[rpc(server)]
+pub trait AdminApi {
+    /// Returns the peers the node is connected to.
+    #[rpc(name = "admin_peers")]
+    fn peers(&self) -> BoxFuture<Result<Vec<PeerInfo>>>;
+
+    /// Returns information about the node.
+    #[rpc(name = "admin_nodeInfo")]
+    fn node_info(&self) -> BoxFuture<Result<NodeInfo>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockAdmin;
+
+    impl AdminApi for MockAdmin {
+        fn peers(&self) -> BoxFuture<Result<Vec<PeerInfo>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn node_info(&self) -> BoxFuture<Result<NodeInfo>> {
+            Box::pin(futures::future::ready(Ok(NodeInfo::default())))
+        }
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(MockAdmin.to_delegate(), &["admin_nodeInfo", "admin_peers"]);
+    }
+}
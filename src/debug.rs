@@ -0,0 +1,365 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Debug rpc interface.
+
+use ethereum_types::{H160, H256};
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+use serde_json::Value;
+
+use crate::types::{
+    AccountRangeResult, BadBlock, BlockNumber, Bytes, CallRequest, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, Index, StorageRangeResult, TxTraceResult,
+};
+
+/// Debug rpc interface.
+#[rpc(server)]
+pub trait DebugApi {
+    /// Returns the trace of the transaction with given hash, as produced by
+    /// the requested tracer.
+    #[rpc(name = "debug_traceTransaction")]
+    fn trace_transaction(
+        &self,
+        _: H256,
+        _: Option<GethDebugTracingOptions>,
+    ) -> BoxFuture<Result<Value>>;
+
+    /// Returns the trace of a call executed on top of the given block,
+    /// without creating a transaction. The options may override the state
+    /// and the block context of the call.
+    #[rpc(name = "debug_traceCall")]
+    fn trace_call(
+        &self,
+        _: CallRequest,
+        _: Option<BlockNumber>,
+        _: Option<GethDebugTracingCallOptions>,
+    ) -> BoxFuture<Result<Value>>;
+
+    /// Returns the traces of every transaction of the block with given
+    /// number, in order.
+    #[rpc(name = "debug_traceBlockByNumber")]
+    fn trace_block_by_number(
+        &self,
+        _: BlockNumber,
+        _: Option<GethDebugTracingOptions>,
+    ) -> BoxFuture<Result<Vec<TxTraceResult>>>;
+
+    /// Returns the traces of every transaction of the block with given hash,
+    /// in order.
+    #[rpc(name = "debug_traceBlockByHash")]
+    fn trace_block_by_hash(
+        &self,
+        _: H256,
+        _: Option<GethDebugTracingOptions>,
+    ) -> BoxFuture<Result<Vec<TxTraceResult>>>;
+
+    /// Returns the traces of every transaction of the given RLP encoded
+    /// block, in order.
+    #[rpc(name = "debug_traceBlock")]
+    fn trace_block(
+        &self,
+        _: Bytes,
+        _: Option<GethDebugTracingOptions>,
+    ) -> BoxFuture<Result<Vec<TxTraceResult>>>;
+
+    /// Returns the signed RLP encoding of the transaction with given hash,
+    /// or `None` if the transaction is unknown.
+    #[rpc(name = "debug_getRawTransaction")]
+    fn raw_transaction(&self, _: H256) -> BoxFuture<Result<Option<Bytes>>>;
+
+    /// Returns the RLP encoding of the block with given number.
+    ///
+    /// Implementations without a pending block should answer `pending`
+    /// with an invalid params error.
+    #[rpc(name = "debug_getRawBlock")]
+    fn raw_block(&self, _: BlockNumber) -> BoxFuture<Result<Bytes>>;
+
+    /// Returns the RLP encoding of the header of the block with given number.
+    ///
+    /// Implementations without a pending block should answer `pending`
+    /// with an invalid params error.
+    #[rpc(name = "debug_getRawHeader")]
+    fn raw_header(&self, _: BlockNumber) -> BoxFuture<Result<Bytes>>;
+
+    /// Returns the consensus encoding of every receipt of the block with
+    /// given number, in transaction order.
+    #[rpc(name = "debug_getRawReceipts")]
+    fn raw_receipts(&self, _: BlockNumber) -> BoxFuture<Result<Vec<Bytes>>>;
+
+    /// Returns up to `limit` storage slots of the given contract, starting
+    /// at the given hashed key, as seen after the transaction at the given
+    /// index of the block with given hash.
+    #[rpc(name = "debug_storageRangeAt")]
+    fn storage_range_at(
+        &self,
+        _: H256,
+        _: Index,
+        _: H160,
+        _: H256,
+        _: u64,
+    ) -> BoxFuture<Result<StorageRangeResult>>;
+
+    /// Returns up to the given number of accounts of the state at the given
+    /// block, in trie order, starting at the given key. The flags skip code,
+    /// skip storage and include accounts whose address preimage is unknown.
+    #[rpc(name = "debug_accountRange")]
+    fn account_range(
+        &self,
+        _: BlockNumber,
+        _: Bytes,
+        _: u64,
+        _: bool,
+        _: bool,
+        _: bool,
+    ) -> BoxFuture<Result<AccountRangeResult>>;
+
+    /// Returns the blocks that failed validation during import.
+    #[rpc(name = "debug_getBadBlocks")]
+    fn bad_blocks(&self) -> BoxFuture<Result<Vec<BadBlock>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::{Error, IoHandler};
+    use serde_json::json;
+
+    struct MockDebug;
+
+    impl DebugApi for MockDebug {
+        fn trace_transaction(
+            &self,
+            _: H256,
+            _: Option<GethDebugTracingOptions>,
+        ) -> BoxFuture<Result<Value>> {
+            Box::pin(futures::future::ready(Ok(Value::Null)))
+        }
+
+        fn trace_call(
+            &self,
+            request: CallRequest,
+            number: Option<BlockNumber>,
+            options: Option<GethDebugTracingCallOptions>,
+        ) -> BoxFuture<Result<Value>> {
+            let options = options.unwrap_or_default();
+            let res = json!({
+                "to": request.to,
+                "number": number,
+                "tracer": options.tracing_options.tracer,
+                "blockOverrides": options.block_overrides,
+            });
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn trace_block_by_number(
+            &self,
+            _: BlockNumber,
+            _: Option<GethDebugTracingOptions>,
+        ) -> BoxFuture<Result<Vec<TxTraceResult>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn trace_block_by_hash(
+            &self,
+            _: H256,
+            _: Option<GethDebugTracingOptions>,
+        ) -> BoxFuture<Result<Vec<TxTraceResult>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn trace_block(
+            &self,
+            _: Bytes,
+            _: Option<GethDebugTracingOptions>,
+        ) -> BoxFuture<Result<Vec<TxTraceResult>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn raw_transaction(&self, hash: H256) -> BoxFuture<Result<Option<Bytes>>> {
+            let res = match hash.is_zero() {
+                true => None,
+                false => Some(Bytes::new(vec![0xf8, 0x6b])),
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn raw_block(&self, number: BlockNumber) -> BoxFuture<Result<Bytes>> {
+            let res = match number {
+                BlockNumber::Num(n) => Ok(Bytes::new(vec![n as u8])),
+                BlockNumber::Latest => Ok(Bytes::new(vec![0xff])),
+                _ => Err(Error::invalid_params("Pending block is not supported")),
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn raw_header(&self, number: BlockNumber) -> BoxFuture<Result<Bytes>> {
+            self.raw_block(number)
+        }
+
+        fn raw_receipts(&self, _: BlockNumber) -> BoxFuture<Result<Vec<Bytes>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn storage_range_at(
+            &self,
+            _: H256,
+            _: Index,
+            _: H160,
+            _: H256,
+            _: u64,
+        ) -> BoxFuture<Result<StorageRangeResult>> {
+            Box::pin(futures::future::ready(Ok(StorageRangeResult::default())))
+        }
+
+        fn account_range(
+            &self,
+            _: BlockNumber,
+            _: Bytes,
+            _: u64,
+            _: bool,
+            _: bool,
+            _: bool,
+        ) -> BoxFuture<Result<AccountRangeResult>> {
+            Box::pin(futures::future::ready(Ok(AccountRangeResult::default())))
+        }
+
+        fn bad_blocks(&self) -> BoxFuture<Result<Vec<BadBlock>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockDebug.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn trace_call_params() {
+        // As sent by Foundry.
+        assert_eq!(
+            call(
+                "debug_traceCall",
+                r#"[{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","data":"0x06fdde03"},"0x10",{"tracer":"callTracer"}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":{"blockOverrides":null,"number":"0x10","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","tracer":"callTracer"},"id":1}"#
+        );
+        assert_eq!(
+            call(
+                "debug_traceCall",
+                r#"[{"to":"0x5fbdb2315678afecb367f032d93f642f64180aa3"}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":{"blockOverrides":null,"number":null,"to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","tracer":null},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn trace_call_block_overrides() {
+        assert_eq!(
+            call(
+                "debug_traceCall",
+                r#"[{"to":"0x5fbdb2315678afecb367f032d93f642f64180aa3"},"latest",{"tracer":"callTracer","stateOverrides":{"0x5fbdb2315678afecb367f032d93f642f64180aa3":{"balance":"0x1"}},"blockOverrides":{"number":"0x1312d00","time":1722000000}}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":{"blockOverrides":{"number":"0x1312d00","time":"0x66a3a280"},"number":"latest","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","tracer":"callTracer"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn raw_transaction_result() {
+        assert_eq!(
+            call(
+                "debug_getRawTransaction",
+                r#"["0xd6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":"0xf86b","id":1}"#
+        );
+        assert_eq!(
+            call(
+                "debug_getRawTransaction",
+                r#"["0x0000000000000000000000000000000000000000000000000000000000000000"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn raw_block_params() {
+        assert_eq!(
+            call("debug_getRawBlock", r#"["latest"]"#),
+            r#"{"jsonrpc":"2.0","result":"0xff","id":1}"#
+        );
+        assert_eq!(
+            call("debug_getRawBlock", r#"["0x10"]"#),
+            r#"{"jsonrpc":"2.0","result":"0x10","id":1}"#
+        );
+        assert_eq!(
+            call("debug_getRawHeader", "[16]"),
+            r#"{"jsonrpc":"2.0","result":"0x10","id":1}"#
+        );
+        assert_eq!(
+            call("debug_getRawHeader", r#"["pending"]"#),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Pending block is not supported"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn raw_receipts_of_empty_block() {
+        assert_eq!(
+            call("debug_getRawReceipts", r#"["0x0"]"#),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn storage_range_at_params() {
+        // As sent by Remix.
+        assert_eq!(
+            call(
+                "debug_storageRangeAt",
+                r#"["0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70",0,"0x5fbdb2315678afecb367f032d93f642f64180aa3","0x0000000000000000000000000000000000000000000000000000000000000000",1024]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":{"nextKey":null,"storage":{}},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockDebug.to_delegate(),
+            &[
+                "debug_accountRange",
+                "debug_getBadBlocks",
+                "debug_getRawBlock",
+                "debug_getRawHeader",
+                "debug_getRawReceipts",
+                "debug_getRawTransaction",
+                "debug_storageRangeAt",
+                "debug_traceBlock",
+                "debug_traceBlockByHash",
+                "debug_traceBlockByNumber",
+                "debug_traceCall",
+                "debug_traceTransaction",
+            ],
+        );
+    }
+}
@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Dev rpc interface, for development chains and test suites.
+
+use ethereum_types::{H160, H256, U256};
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{Bytes, DevTime, Quantity, SignedQuantity};
+
+/// Dev rpc interface.
+#[rpc(server)]
+pub trait DevApi {
+    /// Mines a block, with the given timestamp if any. Returns `"0x0"` for
+    /// ganache compatibility.
+    #[rpc(name = "evm_mine")]
+    fn mine(&self, _: Option<Quantity>) -> BoxFuture<Result<String>>;
+
+    /// Moves the clock forward by the given number of seconds. Returns the
+    /// total time adjustment in seconds: a number for ganache and anvil
+    /// (`DevTime::Ganache`), a decimal string for Hardhat
+    /// (`DevTime::Hardhat`). Negative values are passed through so the
+    /// implementation can reject them with a proper error.
+    #[rpc(name = "evm_increaseTime")]
+    fn increase_time(&self, _: SignedQuantity) -> BoxFuture<Result<DevTime>>;
+
+    /// Sets the timestamp of the next block. Returns the timestamp as a
+    /// decimal string for Hardhat (`Some(DevTime::Hardhat)`), or `null` for
+    /// anvil (`None`).
+    #[rpc(name = "evm_setNextBlockTimestamp")]
+    fn set_next_block_timestamp(&self, _: SignedQuantity) -> BoxFuture<Result<Option<DevTime>>>;
+
+    /// Snapshots the state of the chain. Returns the snapshot id.
+    #[rpc(name = "evm_snapshot")]
+    fn snapshot(&self) -> BoxFuture<Result<U256>>;
+
+    /// Reverts the state of the chain to the given snapshot. Reverting
+    /// invalidates the snapshot itself and every snapshot taken after it, so
+    /// a later revert to any of them returns `false`.
+    #[rpc(name = "evm_revert")]
+    fn revert(&self, _: Quantity) -> BoxFuture<Result<bool>>;
+
+    /// Sets the balance of the given account.
+    #[rpc(name = "hardhat_setBalance", alias("anvil_setBalance"))]
+    fn set_balance(&self, _: H160, _: U256) -> BoxFuture<Result<bool>>;
+
+    /// Sets the code of the given account.
+    #[rpc(name = "hardhat_setCode", alias("anvil_setCode"))]
+    fn set_code(&self, _: H160, _: Bytes) -> BoxFuture<Result<bool>>;
+
+    /// Sets the nonce of the given account.
+    #[rpc(name = "hardhat_setNonce", alias("anvil_setNonce"))]
+    fn set_nonce(&self, _: H160, _: U256) -> BoxFuture<Result<bool>>;
+
+    /// Sets the value of a storage slot of the given account. The slot is
+    /// either a 32-byte hex value or a quantity.
+    #[rpc(name = "hardhat_setStorageAt", alias("anvil_setStorageAt"))]
+    fn set_storage_at(&self, _: H160, _: Quantity, _: H256) -> BoxFuture<Result<bool>>;
+
+    /// Allows sending transactions from the given account without its key.
+    #[rpc(name = "anvil_impersonateAccount", alias("hardhat_impersonateAccount"))]
+    fn impersonate_account(&self, _: H160) -> BoxFuture<Result<()>>;
+
+    /// Stops impersonating the given account.
+    #[rpc(
+        name = "anvil_stopImpersonatingAccount",
+        alias("hardhat_stopImpersonatingAccount")
+    )]
+    fn stop_impersonating_account(&self, _: H160) -> BoxFuture<Result<()>>;
+
+    /// Enables or disables impersonating every account. Hardhat has no
+    /// equivalent.
+    #[rpc(name = "anvil_autoImpersonateAccount")]
+    fn auto_impersonate_account(&self, _: bool) -> BoxFuture<Result<()>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::IoHandler;
+
+    // Answers the time methods in the shape of the given tool.
+    struct MockDev(fn(i64) -> DevTime);
+
+    impl DevApi for MockDev {
+        fn mine(&self, _: Option<Quantity>) -> BoxFuture<Result<String>> {
+            Box::pin(futures::future::ready(Ok("0x0".to_string())))
+        }
+
+        // Pretends the clock was already adjusted by 100 seconds.
+        fn increase_time(&self, seconds: SignedQuantity) -> BoxFuture<Result<DevTime>> {
+            Box::pin(futures::future::ready(Ok(self.0(100 + seconds.value()))))
+        }
+
+        // Returns `null` in the ganache shape, like anvil does.
+        fn set_next_block_timestamp(
+            &self,
+            timestamp: SignedQuantity,
+        ) -> BoxFuture<Result<Option<DevTime>>> {
+            let res = if timestamp.value() < 0 {
+                Err(jsonrpc_core::Error::invalid_params("timestamp is negative"))
+            } else {
+                match self.0(timestamp.value()) {
+                    DevTime::Ganache(_) => Ok(None),
+                    time => Ok(Some(time)),
+                }
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn snapshot(&self) -> BoxFuture<Result<U256>> {
+            Box::pin(futures::future::ready(Ok(U256::from(26))))
+        }
+
+        // Only the snapshot returned above exists.
+        fn revert(&self, id: Quantity) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(id.value() == U256::from(26))))
+        }
+
+        fn set_balance(&self, _: H160, _: U256) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn set_code(&self, _: H160, _: Bytes) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn set_nonce(&self, _: H160, _: U256) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        // Only slot 1 may be written.
+        fn set_storage_at(&self, _: H160, slot: Quantity, _: H256) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(slot.value() == U256::one())))
+        }
+
+        fn impersonate_account(&self, _: H160) -> BoxFuture<Result<()>> {
+            Box::pin(futures::future::ready(Ok(())))
+        }
+
+        fn stop_impersonating_account(&self, _: H160) -> BoxFuture<Result<()>> {
+            Box::pin(futures::future::ready(Ok(())))
+        }
+
+        fn auto_impersonate_account(&self, _: bool) -> BoxFuture<Result<()>> {
+            Box::pin(futures::future::ready(Ok(())))
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        call_as(DevTime::Ganache, method, params)
+    }
+
+    fn call_as(shape: fn(i64) -> DevTime, method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockDev(shape).to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn mine_params() {
+        assert_eq!(
+            call("evm_mine", "[]"),
+            r#"{"jsonrpc":"2.0","result":"0x0","id":1}"#
+        );
+        // As sent by Hardhat and by ganache.
+        for params in ["[1722000000]", r#"["0x66a3a280"]"#] {
+            assert_eq!(
+                call("evm_mine", params),
+                r#"{"jsonrpc":"2.0","result":"0x0","id":1}"#
+            );
+        }
+        assert!(call("evm_mine", r#"["soon"]"#).contains(r#""code":-32602"#));
+    }
+
+    #[test]
+    fn increase_time_params() {
+        for params in ["[60]", r#"["0x3c"]"#] {
+            assert_eq!(
+                call("evm_increaseTime", params),
+                r#"{"jsonrpc":"2.0","result":160,"id":1}"#
+            );
+        }
+        for params in ["[-60]", r#"["-0x3c"]"#] {
+            assert_eq!(
+                call("evm_increaseTime", params),
+                r#"{"jsonrpc":"2.0","result":40,"id":1}"#
+            );
+        }
+        assert_eq!(
+            call_as(DevTime::Hardhat, "evm_increaseTime", "[60]"),
+            r#"{"jsonrpc":"2.0","result":"160","id":1}"#
+        );
+    }
+
+    #[test]
+    fn set_next_block_timestamp_params() {
+        for params in ["[1722000000]", r#"["0x66a3a280"]"#] {
+            assert_eq!(
+                call("evm_setNextBlockTimestamp", params),
+                r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+            );
+            assert_eq!(
+                call_as(DevTime::Hardhat, "evm_setNextBlockTimestamp", params),
+                r#"{"jsonrpc":"2.0","result":"1722000000","id":1}"#
+            );
+        }
+        assert_eq!(
+            call("evm_setNextBlockTimestamp", "[-1]"),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"timestamp is negative"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn snapshot_result() {
+        assert_eq!(
+            call("evm_snapshot", "[]"),
+            r#"{"jsonrpc":"2.0","result":"0x1a","id":1}"#
+        );
+    }
+
+    #[test]
+    fn revert_params() {
+        for params in ["[26]", r#"["0x1a"]"#] {
+            assert_eq!(
+                call("evm_revert", params),
+                r#"{"jsonrpc":"2.0","result":true,"id":1}"#
+            );
+        }
+        assert_eq!(
+            call("evm_revert", r#"["0x1b"]"#),
+            r#"{"jsonrpc":"2.0","result":false,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn set_state_params() {
+        let address = r#""0x0000000000000000000000000000000000000001""#;
+        let value = r#""0x000000000000000000000000000000000000000000000000000000000000002a""#;
+        let cases = [
+            // More than fits a u64.
+            (
+                "setBalance",
+                format!(r#"[{}, "0x10000000000000000000000000000"]"#, address),
+            ),
+            ("setCode", format!(r#"[{}, "0x6080"]"#, address)),
+            ("setNonce", format!(r#"[{}, "0x2a"]"#, address)),
+            (
+                "setStorageAt",
+                format!(r#"[{}, "0x1", {}]"#, address, value),
+            ),
+            (
+                "setStorageAt",
+                format!(
+                    r#"[{}, "0x0000000000000000000000000000000000000000000000000000000000000001", {}]"#,
+                    address, value
+                ),
+            ),
+        ];
+        for (method, params) in cases.iter() {
+            for prefix in ["hardhat", "anvil"] {
+                assert_eq!(
+                    call(&format!("{}_{}", prefix, method), params),
+                    r#"{"jsonrpc":"2.0","result":true,"id":1}"#
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn impersonate_result() {
+        let address = r#"["0x0000000000000000000000000000000000000001"]"#;
+        for method in [
+            "anvil_impersonateAccount",
+            "hardhat_impersonateAccount",
+            "anvil_stopImpersonatingAccount",
+            "hardhat_stopImpersonatingAccount",
+        ] {
+            assert_eq!(
+                call(method, address),
+                r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+            );
+        }
+        assert_eq!(
+            call("anvil_autoImpersonateAccount", "[true]"),
+            r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockDev(DevTime::Ganache).to_delegate(),
+            &[
+                "anvil_autoImpersonateAccount",
+                "anvil_impersonateAccount",
+                "anvil_setBalance",
+                "anvil_setCode",
+                "anvil_setNonce",
+                "anvil_setStorageAt",
+                "anvil_stopImpersonatingAccount",
+                "evm_increaseTime",
+                "evm_mine",
+                "evm_revert",
+                "evm_setNextBlockTimestamp",
+                "evm_snapshot",
+                "hardhat_impersonateAccount",
+                "hardhat_setBalance",
+                "hardhat_setCode",
+                "hardhat_setNonce",
+                "hardhat_setStorageAt",
+                "hardhat_stopImpersonatingAccount",
+            ],
+        );
+    }
+}
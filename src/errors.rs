@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Errors shared by rpc implementations.
+
+use ethereum_types::U64;
+use jsonrpc_core::{Error, ErrorCode};
+use serde_json::json;
+
+/// Error code for a query returning more results than the node allows.
+pub const QUERY_LIMIT_EXCEEDED: i64 = -32005;
+
+/// Builds the error for a log query returning more than `limit` results.
+/// The data suggests the block range `[from, to]` to retry with, which
+/// clients such as ethers.js use to split the query.
+pub fn query_limit_exceeded(limit: usize, from: U64, to: U64) -> Error {
+    Error {
+        code: ErrorCode::ServerError(QUERY_LIMIT_EXCEEDED),
+        message: format!(
+            "query returned more than {} results. Try with this block range [{:#x}, {:#x}].",
+            limit, from, to
+        ),
+        data: Some(json!({
+            "from": from,
+            "limit": limit,
+            "to": to,
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_limit_exceeded_serialize() {
+        let error = query_limit_exceeded(10000, U64::from(0xcb5e86), U64::from(0xcb66f5));
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"code":-32005,"message":"query returned more than 10000 results. Try with this block range [0xcb5e86, 0xcb66f5].","data":{"from":"0xcb5e86","limit":10000,"to":"0xcb66f5"}}"#
+        );
+    }
+}
@@ -19,24 +19,27 @@
 //! Eth rpc interface.
 
 use ethereum_types::{H160, H256, H64, U256, U64};
-use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_core::{BoxFuture, Metadata, RemoteProcedure, Result};
 use jsonrpc_derive::rpc;
 
 use crate::types::{
-    BlockNumber, Bytes, CallRequest, Filter, Index, Log, Receipt, RichBlock, SyncStatus,
-    Transaction, TransactionRequest, Work,
+    AccessListResult, Account, BlockNumber, BlockOverrides, Bytes, CallRequest, CallStateOverride,
+    EIP1186ProofResponse, FeeHistory, Filter, Header, Index, Log, Quantity, Receipt, RichBlock,
+    RichRawTransaction, SimulatePayload, SimulatedBlock, SyncStatus, Transaction,
+    TransactionRequest, TypedData, Work,
 };
 
 /// Eth rpc interface.
 #[rpc(server)]
 pub trait EthApi {
-    /// Returns protocol version encoded as a string (quotes are necessary).
+    /// Returns protocol version encoded as a string (quotes are necessary),
+    /// e.g. `"0x41"`.
     #[rpc(name = "eth_protocolVersion")]
-    fn protocol_version(&self) -> BoxFuture<Result<u64>>;
+    fn protocol_version(&self) -> BoxFuture<Result<String>>;
 
     /// Returns the number of hashes per second that the node is mining with.
     #[rpc(name = "eth_hashrate")]
-    fn hashrate(&self) -> Result<U256>;
+    fn hashrate(&self) -> BoxFuture<Result<U256>>;
 
     /// Returns the chain ID used for transaction signing at the
     /// current best block. None is returned if not
@@ -44,22 +47,35 @@ pub trait EthApi {
     #[rpc(name = "eth_chainId")]
     fn chain_id(&self) -> BoxFuture<Result<Option<U64>>>;
 
-    /// Returns accounts list.
-    #[rpc(name = "eth_accounts")]
-    fn accounts(&self) -> Result<Vec<H160>>;
-
     /// Returns balance of the given account.
     #[rpc(name = "eth_getBalance")]
     fn balance(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<U256>>;
 
-    /// Sends transaction; will block waiting for signer to return the
-    /// transaction hash.
-    #[rpc(name = "eth_sendTransaction")]
-    fn send_transaction(&self, _: TransactionRequest) -> BoxFuture<Result<H256>>;
+    /// Returns the balance, nonce, code hash and storage root of the given
+    /// account. Accounts that don't exist are returned empty.
+    #[rpc(name = "eth_getAccount")]
+    fn account(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<Account>>;
 
-    /// Call contract, returning the output data.
+    /// Call contract, returning the output data. The state the call runs
+    /// on can be altered with a set of account overrides, and the block
+    /// context with block overrides.
     #[rpc(name = "eth_call")]
-    fn call(&self, _: CallRequest, _: Option<BlockNumber>) -> BoxFuture<Result<Bytes>>;
+    fn call(
+        &self,
+        _: CallRequest,
+        _: Option<BlockNumber>,
+        _: Option<CallStateOverride>,
+        _: Option<BlockOverrides>,
+    ) -> BoxFuture<Result<Bytes>>;
+
+    /// Simulates the given blocks of calls on top of the given block, each
+    /// block and call seeing the state changes of the previous ones.
+    #[rpc(name = "eth_simulateV1")]
+    fn simulate_v1(
+        &self,
+        _: SimulatePayload,
+        _: Option<BlockNumber>,
+    ) -> BoxFuture<Result<Vec<SimulatedBlock>>>;
 
     /// Returns an object with data about the sync status or false. (wtf?)
     #[rpc(name = "eth_syncing")]
@@ -77,6 +93,27 @@ pub trait EthApi {
     #[rpc(name = "eth_gasPrice")]
     fn gas_price(&self) -> BoxFuture<Result<U256>>;
 
+    /// Returns a suggestion of the priority fee per gas for dynamic fee
+    /// transactions.
+    #[rpc(name = "eth_maxPriorityFeePerGas")]
+    fn max_priority_fee_per_gas(&self) -> BoxFuture<Result<U256>>;
+
+    /// Returns the base fee per blob gas of the next block. Chains without
+    /// EIP-4844 blobs should return zero, or an error for clients to treat
+    /// blobs as unsupported, rather than leave the method unregistered.
+    #[rpc(name = "eth_blobBaseFee")]
+    fn blob_base_fee(&self) -> BoxFuture<Result<U256>>;
+
+    /// Returns the fee history of the given amount of blocks up to the newest
+    /// one, with the priority fees at the given percentiles of each block.
+    #[rpc(name = "eth_feeHistory")]
+    fn fee_history(
+        &self,
+        _: Quantity,
+        _: BlockNumber,
+        _: Option<Vec<f64>>,
+    ) -> BoxFuture<Result<FeeHistory>>;
+
     /// Returns highest block number.
     #[rpc(name = "eth_blockNumber")]
     fn block_number(&self) -> BoxFuture<Result<U256>>;
@@ -93,6 +130,14 @@ pub trait EthApi {
     #[rpc(name = "eth_getBlockByNumber")]
     fn block_by_number(&self, _: BlockNumber, _: bool) -> BoxFuture<Result<Option<RichBlock>>>;
 
+    /// Returns header of the block with given hash.
+    #[rpc(name = "eth_getHeaderByHash")]
+    fn header_by_hash(&self, _: H256) -> BoxFuture<Result<Option<Header>>>;
+
+    /// Returns header of the block with given number.
+    #[rpc(name = "eth_getHeaderByNumber")]
+    fn header_by_number(&self, _: BlockNumber) -> BoxFuture<Result<Option<Header>>>;
+
     /// Returns the number of transactions sent from given address at given time (block number).
     #[rpc(name = "eth_getTransactionCount")]
     fn transaction_count(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<U256>>;
@@ -107,11 +152,21 @@ pub trait EthApi {
 
     /// Returns the number of uncles in a block with given hash.
     #[rpc(name = "eth_getUncleCountByBlockHash")]
-    fn block_uncles_count_by_hash(&self, _: H256) -> Result<U256>;
+    fn block_uncles_count_by_hash(&self, _: H256) -> BoxFuture<Result<U256>>;
 
     /// Returns the number of uncles in a block with given block number.
     #[rpc(name = "eth_getUncleCountByBlockNumber")]
-    fn block_uncles_count_by_number(&self, _: BlockNumber) -> Result<U256>;
+    fn block_uncles_count_by_number(&self, _: BlockNumber) -> BoxFuture<Result<U256>>;
+
+    /// Returns the account and storage values of the given account, with
+    /// Merkle proofs of the given storage keys.
+    #[rpc(name = "eth_getProof")]
+    fn proof(
+        &self,
+        _: H160,
+        _: Vec<H256>,
+        _: Option<BlockNumber>,
+    ) -> BoxFuture<Result<EIP1186ProofResponse>>;
 
     /// Returns the code at given address at given time (block number).
     #[rpc(name = "eth_getCode")]
@@ -121,9 +176,28 @@ pub trait EthApi {
     #[rpc(name = "eth_sendRawTransaction")]
     fn send_raw_transaction(&self, _: Bytes) -> BoxFuture<Result<H256>>;
 
-    /// Estimate gas needed for execution of given contract.
+    /// Estimate gas needed for execution of given contract, on top of the
+    /// state altered by the given account overrides.
+    ///
+    /// Implementers that can't honor some override, e.g. a full `state`
+    /// replacement, should reject the request with an error rather than
+    /// ignore the override and return an estimate against the actual state.
     #[rpc(name = "eth_estimateGas")]
-    fn estimate_gas(&self, _: CallRequest, _: Option<BlockNumber>) -> BoxFuture<Result<U256>>;
+    fn estimate_gas(
+        &self,
+        _: CallRequest,
+        _: Option<BlockNumber>,
+        _: Option<CallStateOverride>,
+    ) -> BoxFuture<Result<U256>>;
+
+    /// Returns the access list of the given call, and the gas it uses with
+    /// that list.
+    #[rpc(name = "eth_createAccessList")]
+    fn create_access_list(
+        &self,
+        _: CallRequest,
+        _: Option<BlockNumber>,
+    ) -> BoxFuture<Result<AccessListResult>>;
 
     /// Get transaction by its hash.
     #[rpc(name = "eth_getTransactionByHash")]
@@ -145,13 +219,27 @@ pub trait EthApi {
         _: Index,
     ) -> BoxFuture<Result<Option<Transaction>>>;
 
+    /// Returns the pending transactions sent from the node's accounts. Their
+    /// block hash, block number and transaction index are `null`.
+    #[rpc(name = "eth_pendingTransactions")]
+    fn pending_transactions(&self) -> BoxFuture<Result<Vec<Transaction>>>;
+
     /// Returns transaction receipt by transaction hash.
     #[rpc(name = "eth_getTransactionReceipt")]
     fn transaction_receipt(&self, _: H256) -> BoxFuture<Result<Option<Receipt>>>;
 
+    /// Returns the receipts of every transaction of the given block, or
+    /// `None` if the block is unknown. The block may be given by hash.
+    #[rpc(name = "eth_getBlockReceipts")]
+    fn block_receipts(&self, _: BlockNumber) -> BoxFuture<Result<Option<Vec<Receipt>>>>;
+
     /// Returns an uncles at given block and index.
     #[rpc(name = "eth_getUncleByBlockHashAndIndex")]
-    fn uncle_by_block_hash_and_index(&self, _: H256, _: Index) -> Result<Option<RichBlock>>;
+    fn uncle_by_block_hash_and_index(
+        &self,
+        _: H256,
+        _: Index,
+    ) -> BoxFuture<Result<Option<RichBlock>>>;
 
     /// Returns an uncles at given block and index.
     #[rpc(name = "eth_getUncleByBlockNumberAndIndex")]
@@ -159,7 +247,7 @@ pub trait EthApi {
         &self,
         _: BlockNumber,
         _: Index,
-    ) -> Result<Option<RichBlock>>;
+    ) -> BoxFuture<Result<Option<RichBlock>>>;
 
     /// Returns logs matching given filter object.
     #[rpc(name = "eth_getLogs")]
@@ -167,13 +255,744 @@ pub trait EthApi {
 
     /// Returns the hash of the current block, the seedHash, and the boundary condition to be met.
     #[rpc(name = "eth_getWork")]
-    fn work(&self) -> Result<Work>;
+    fn work(&self) -> BoxFuture<Result<Work>>;
 
     /// Used for submitting a proof-of-work solution.
     #[rpc(name = "eth_submitWork")]
-    fn submit_work(&self, _: H64, _: H256, _: H256) -> Result<bool>;
+    fn submit_work(&self, _: H64, _: H256, _: H256) -> BoxFuture<Result<bool>>;
 
     /// Used for submitting mining hashrate.
     #[rpc(name = "eth_submitHashrate")]
-    fn submit_hashrate(&self, _: U256, _: H256) -> Result<bool>;
+    fn submit_hashrate(&self, _: U256, _: H256) -> BoxFuture<Result<bool>>;
+}
+
+/// Eth rpc interface for the methods that depend on accounts managed by the
+/// node. Nodes without local accounts may implement only `EthApi`; full
+/// nodes implement both and register them with `EthFullApi`.
+#[rpc(server)]
+pub trait EthSigningApi {
+    /// Returns accounts list.
+    #[rpc(name = "eth_accounts")]
+    fn accounts(&self) -> BoxFuture<Result<Vec<H160>>>;
+
+    /// Sends transaction; will block waiting for signer to return the
+    /// transaction hash.
+    #[rpc(name = "eth_sendTransaction")]
+    fn send_transaction(&self, _: TransactionRequest) -> BoxFuture<Result<H256>>;
+
+    /// Signs the given message with the given account, returning the 65-byte
+    /// signature. The implementation hashes the message with the EIP-191
+    /// prefix `"\x19Ethereum Signed Message:\n" + len(message)` before
+    /// signing.
+    #[rpc(name = "eth_sign")]
+    fn sign(&self, _: H160, _: Bytes) -> BoxFuture<Result<Bytes>>;
+
+    /// Signs the given EIP-712 typed data with the given account, returning
+    /// the 65-byte signature.
+    #[rpc(name = "eth_signTypedData", alias("eth_signTypedData_v4"))]
+    fn sign_typed_data(&self, _: H160, _: TypedData) -> BoxFuture<Result<Bytes>>;
+
+    /// Signs the given transaction with its sender account without sending
+    /// it, returning the raw transaction and its decoded form.
+    #[rpc(name = "eth_signTransaction")]
+    fn sign_transaction(&self, _: TransactionRequest) -> BoxFuture<Result<RichRawTransaction>>;
+}
+
+/// Eth rpc interface of a full node, serving both `EthApi` and
+/// `EthSigningApi`. Implemented for every type implementing both.
+pub trait EthFullApi: EthApi + EthSigningApi + Clone {
+    /// Returns the methods of both interfaces, to be registered at once with
+    /// `IoHandler::extend_with`.
+    fn to_full_delegate<M: Metadata>(self) -> Vec<(String, RemoteProcedure<M>)> {
+        EthApi::to_delegate(self.clone())
+            .into_iter()
+            .chain(EthSigningApi::to_delegate(self))
+            .collect()
+    }
+}
+
+impl<T: EthApi + EthSigningApi + Clone> EthFullApi for T {}
+
+/// Eth rpc interface for the methods outside the Ethereum JSON-RPC
+/// specification. Spec-strict deployments do not register it.
+#[rpc(server)]
+pub trait EthExtApi {
+    /// Returns the transaction sent by the given address with the given
+    /// nonce, or `None` if that nonce has not been used yet.
+    #[rpc(name = "eth_getTransactionBySenderAndNonce")]
+    fn transaction_by_sender_and_nonce(
+        &self,
+        _: H160,
+        _: U64,
+    ) -> BoxFuture<Result<Option<Transaction>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::{Error, IoHandler};
+
+    // Answers the methods the mocks don't stub.
+    fn not_found<T: Send + 'static>() -> BoxFuture<Result<T>> {
+        Box::pin(futures::future::err(Error::method_not_found()))
+    }
+
+    // Stands in for the local accounts of a node.
+    #[derive(Clone)]
+    struct Signer;
+
+    // `MockEth(())` is a read-only node implementing `EthApi` alone, while
+    // `MockEth(Signer)` also implements `EthSigningApi`.
+    #[derive(Clone)]
+    struct MockEth<S>(S);
+
+    impl<S: Clone + Send + Sync + 'static> EthApi for MockEth<S> {
+        fn block_receipts(&self, number: BlockNumber) -> BoxFuture<Result<Option<Vec<Receipt>>>> {
+            let res = match number {
+                BlockNumber::Num(_) => None,
+                _ => Some(vec![]),
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn protocol_version(&self) -> BoxFuture<Result<String>> {
+            Box::pin(futures::future::ready(Ok(format!("{:#x}", 65))))
+        }
+
+        fn hashrate(&self) -> BoxFuture<Result<U256>> {
+            Box::pin(async { Ok(U256::zero()) })
+        }
+
+        fn chain_id(&self) -> BoxFuture<Result<Option<U64>>> {
+            not_found()
+        }
+
+        fn balance(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        // Returns the overridden code and storage values of the callee, or
+        // the call data when it is not overridden, followed by the
+        // overridden block number.
+        fn call(
+            &self,
+            request: CallRequest,
+            _: Option<BlockNumber>,
+            overrides: Option<CallStateOverride>,
+            block_overrides: Option<BlockOverrides>,
+        ) -> BoxFuture<Result<Bytes>> {
+            let mut output = match request
+                .to
+                .and_then(|to| overrides.and_then(|mut overrides| overrides.remove(&to)))
+            {
+                Some(account) => {
+                    let mut output = account.code.map(|code| code.0).unwrap_or_default();
+                    for value in account.state_diff.unwrap_or_default().values() {
+                        output.extend_from_slice(value.as_bytes());
+                    }
+                    output
+                }
+                None => request.data.unwrap_or_default().0,
+            };
+            if let Some(number) = block_overrides.and_then(|block| block.number) {
+                output.extend_from_slice(&<[u8; 32]>::from(number));
+            }
+            Box::pin(futures::future::ready(Ok(Bytes(output))))
+        }
+
+        fn syncing(&self) -> BoxFuture<Result<SyncStatus>> {
+            not_found()
+        }
+
+        fn author(&self) -> BoxFuture<Result<H160>> {
+            not_found()
+        }
+
+        fn is_mining(&self) -> BoxFuture<Result<bool>> {
+            not_found()
+        }
+
+        fn gas_price(&self) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        fn max_priority_fee_per_gas(&self) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        fn fee_history(
+            &self,
+            _: Quantity,
+            _: BlockNumber,
+            _: Option<Vec<f64>>,
+        ) -> BoxFuture<Result<FeeHistory>> {
+            not_found()
+        }
+
+        fn block_number(&self) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        fn storage_at(&self, _: H160, _: H256, _: Option<BlockNumber>) -> BoxFuture<Result<H256>> {
+            not_found()
+        }
+
+        fn block_by_hash(&self, _: H256, _: bool) -> BoxFuture<Result<Option<RichBlock>>> {
+            not_found()
+        }
+
+        fn block_by_number(&self, _: BlockNumber, _: bool) -> BoxFuture<Result<Option<RichBlock>>> {
+            not_found()
+        }
+
+        fn transaction_count(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        fn block_transaction_count_by_hash(&self, _: H256) -> BoxFuture<Result<Option<U256>>> {
+            not_found()
+        }
+
+        fn block_transaction_count_by_number(
+            &self,
+            _: BlockNumber,
+        ) -> BoxFuture<Result<Option<U256>>> {
+            not_found()
+        }
+
+        fn block_uncles_count_by_hash(&self, _: H256) -> BoxFuture<Result<U256>> {
+            Box::pin(async { Ok(U256::zero()) })
+        }
+
+        fn block_uncles_count_by_number(&self, _: BlockNumber) -> BoxFuture<Result<U256>> {
+            Box::pin(async { Ok(U256::zero()) })
+        }
+
+        fn code_at(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<Bytes>> {
+            not_found()
+        }
+
+        fn send_raw_transaction(&self, _: Bytes) -> BoxFuture<Result<H256>> {
+            not_found()
+        }
+
+        // Rejects full storage replacements, and otherwise adds the number of
+        // overridden accounts to the gas of a transfer.
+        fn estimate_gas(
+            &self,
+            _: CallRequest,
+            _: Option<BlockNumber>,
+            overrides: Option<CallStateOverride>,
+        ) -> BoxFuture<Result<U256>> {
+            let overrides = overrides.unwrap_or_default();
+            let res = if overrides.values().any(|account| account.state.is_some()) {
+                Err(Error::invalid_params("state overrides are not supported"))
+            } else {
+                Ok(U256::from(21_000 + overrides.len()))
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn transaction_by_hash(&self, _: H256) -> BoxFuture<Result<Option<Transaction>>> {
+            not_found()
+        }
+
+        fn transaction_by_block_hash_and_index(
+            &self,
+            _: H256,
+            _: Index,
+        ) -> BoxFuture<Result<Option<Transaction>>> {
+            not_found()
+        }
+
+        fn transaction_by_block_number_and_index(
+            &self,
+            _: BlockNumber,
+            _: Index,
+        ) -> BoxFuture<Result<Option<Transaction>>> {
+            not_found()
+        }
+
+        fn transaction_receipt(&self, _: H256) -> BoxFuture<Result<Option<Receipt>>> {
+            not_found()
+        }
+
+        fn uncle_by_block_hash_and_index(
+            &self,
+            _: H256,
+            _: Index,
+        ) -> BoxFuture<Result<Option<RichBlock>>> {
+            Box::pin(async { Ok(None) })
+        }
+
+        fn uncle_by_block_number_and_index(
+            &self,
+            _: BlockNumber,
+            _: Index,
+        ) -> BoxFuture<Result<Option<RichBlock>>> {
+            Box::pin(async { Ok(None) })
+        }
+
+        fn logs(&self, _: Filter) -> BoxFuture<Result<Vec<Log>>> {
+            not_found()
+        }
+
+        fn work(&self) -> BoxFuture<Result<Work>> {
+            Box::pin(async {
+                Ok(Work {
+                    pow_hash: H256::zero(),
+                    seed_hash: H256::zero(),
+                    target: H256::zero(),
+                    number: None,
+                })
+            })
+        }
+
+        fn submit_work(&self, _: H64, _: H256, _: H256) -> BoxFuture<Result<bool>> {
+            Box::pin(async { Ok(false) })
+        }
+
+        fn submit_hashrate(&self, _: U256, _: H256) -> BoxFuture<Result<bool>> {
+            Box::pin(async { Ok(false) })
+        }
+
+        fn blob_base_fee(&self) -> BoxFuture<Result<U256>> {
+            not_found()
+        }
+
+        fn proof(
+            &self,
+            _: H160,
+            _: Vec<H256>,
+            _: Option<BlockNumber>,
+        ) -> BoxFuture<Result<EIP1186ProofResponse>> {
+            not_found()
+        }
+
+        fn create_access_list(
+            &self,
+            _: CallRequest,
+            _: Option<BlockNumber>,
+        ) -> BoxFuture<Result<AccessListResult>> {
+            not_found()
+        }
+
+        fn account(&self, _: H160, _: Option<BlockNumber>) -> BoxFuture<Result<Account>> {
+            not_found()
+        }
+
+        fn pending_transactions(&self) -> BoxFuture<Result<Vec<Transaction>>> {
+            not_found()
+        }
+
+        fn header_by_hash(&self, _: H256) -> BoxFuture<Result<Option<Header>>> {
+            not_found()
+        }
+
+        fn header_by_number(&self, _: BlockNumber) -> BoxFuture<Result<Option<Header>>> {
+            not_found()
+        }
+
+        fn simulate_v1(
+            &self,
+            _: SimulatePayload,
+            _: Option<BlockNumber>,
+        ) -> BoxFuture<Result<Vec<SimulatedBlock>>> {
+            not_found()
+        }
+    }
+
+    impl EthSigningApi for MockEth<Signer> {
+        fn accounts(&self) -> BoxFuture<Result<Vec<H160>>> {
+            Box::pin(async { Ok(vec![]) })
+        }
+
+        fn send_transaction(&self, _: TransactionRequest) -> BoxFuture<Result<H256>> {
+            not_found()
+        }
+
+        // Echoes the message instead of signing it.
+        fn sign(&self, _: H160, message: Bytes) -> BoxFuture<Result<Bytes>> {
+            Box::pin(futures::future::ready(Ok(message)))
+        }
+
+        fn sign_typed_data(&self, _: H160, _: TypedData) -> BoxFuture<Result<Bytes>> {
+            not_found()
+        }
+
+        fn sign_transaction(&self, _: TransactionRequest) -> BoxFuture<Result<RichRawTransaction>> {
+            not_found()
+        }
+    }
+
+    #[test]
+    fn signing_method_names() {
+        crate::assert_method_names(
+            EthSigningApi::to_delegate(MockEth(Signer)),
+            &[
+                "eth_accounts",
+                "eth_sendTransaction",
+                "eth_sign",
+                "eth_signTransaction",
+                "eth_signTypedData",
+                "eth_signTypedData_v4",
+            ],
+        );
+    }
+
+    #[test]
+    fn full_delegate_method_names() {
+        let names: Vec<String> = MockEth(Signer)
+            .to_full_delegate::<()>()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let eth = EthApi::to_delegate::<()>(MockEth(Signer))
+            .into_iter()
+            .count();
+        let signing = EthSigningApi::to_delegate::<()>(MockEth(Signer))
+            .into_iter()
+            .count();
+        assert_eq!(names.len(), eth + signing);
+        assert!(names.iter().any(|name| name == "eth_blockNumber"));
+        assert!(names.iter().any(|name| name == "eth_sendTransaction"));
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockEth(()).to_delegate(),
+            &[
+                "eth_blobBaseFee",
+                "eth_blockNumber",
+                "eth_call",
+                "eth_chainId",
+                "eth_coinbase",
+                "eth_createAccessList",
+                "eth_estimateGas",
+                "eth_feeHistory",
+                "eth_gasPrice",
+                "eth_getAccount",
+                "eth_getBalance",
+                "eth_getBlockByHash",
+                "eth_getBlockByNumber",
+                "eth_getBlockReceipts",
+                "eth_getBlockTransactionCountByHash",
+                "eth_getBlockTransactionCountByNumber",
+                "eth_getCode",
+                "eth_getHeaderByHash",
+                "eth_getHeaderByNumber",
+                "eth_getLogs",
+                "eth_getProof",
+                "eth_getStorageAt",
+                "eth_getTransactionByBlockHashAndIndex",
+                "eth_getTransactionByBlockNumberAndIndex",
+                "eth_getTransactionByHash",
+                "eth_getTransactionCount",
+                "eth_getTransactionReceipt",
+                "eth_getUncleByBlockHashAndIndex",
+                "eth_getUncleByBlockNumberAndIndex",
+                "eth_getUncleCountByBlockHash",
+                "eth_getUncleCountByBlockNumber",
+                "eth_getWork",
+                "eth_hashrate",
+                "eth_maxPriorityFeePerGas",
+                "eth_mining",
+                "eth_pendingTransactions",
+                "eth_protocolVersion",
+                "eth_sendRawTransaction",
+                "eth_simulateV1",
+                "eth_submitHashrate",
+                "eth_submitWork",
+                "eth_syncing",
+            ],
+        );
+    }
+
+    struct MockExt;
+
+    impl EthExtApi for MockExt {
+        // No transaction was sent yet.
+        fn transaction_by_sender_and_nonce(
+            &self,
+            _: H160,
+            _: U64,
+        ) -> BoxFuture<Result<Option<Transaction>>> {
+            Box::pin(futures::future::ready(Ok(None)))
+        }
+    }
+
+    #[test]
+    fn ext_method_names() {
+        crate::assert_method_names(
+            MockExt.to_delegate(),
+            &["eth_getTransactionBySenderAndNonce"],
+        );
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockEth(Signer).to_full_delegate());
+        io.extend_with(MockExt.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn block_receipts_result() {
+        assert_eq!(
+            call("eth_getBlockReceipts", r#"["latest"]"#),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+        assert_eq!(
+            call(
+                "eth_getBlockReceipts",
+                r#"[{"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add"}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+        assert_eq!(
+            call("eth_getBlockReceipts", r#"["0x2ed119"]"#),
+            r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn sign_params() {
+        assert_eq!(
+            call(
+                "eth_sign",
+                r#"["0x9b2055d370f73ec7d8a03e965129118dc8f5bf83","0x"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":"0x","id":1}"#
+        );
+        let message = format!("0x{}", "00ff".repeat(4096));
+        assert_eq!(
+            call(
+                "eth_sign",
+                &format!(
+                    r#"["0x9b2055d370f73ec7d8a03e965129118dc8f5bf83","{}"]"#,
+                    message
+                )
+            ),
+            format!(r#"{{"jsonrpc":"2.0","result":"{}","id":1}}"#, message)
+        );
+    }
+
+    #[test]
+    fn transaction_by_sender_and_nonce_result() {
+        assert_eq!(
+            call(
+                "eth_getTransactionBySenderAndNonce",
+                r#"["0x9b2055d370f73ec7d8a03e965129118dc8f5bf83","0x0"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn protocol_version_result() {
+        assert_eq!(
+            call("eth_protocolVersion", "[]"),
+            r#"{"jsonrpc":"2.0","result":"0x41","id":1}"#
+        );
+    }
+
+    #[test]
+    fn async_methods_result() {
+        let zero = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        let cases = [
+            ("eth_hashrate", "[]".to_string(), r#""0x0""#.to_string()),
+            (
+                "eth_getUncleCountByBlockHash",
+                format!(r#"["{}"]"#, zero),
+                r#""0x0""#.to_string(),
+            ),
+            (
+                "eth_getUncleCountByBlockNumber",
+                r#"["latest"]"#.to_string(),
+                r#""0x0""#.to_string(),
+            ),
+            (
+                "eth_getUncleByBlockHashAndIndex",
+                format!(r#"["{}","0x0"]"#, zero),
+                "null".to_string(),
+            ),
+            (
+                "eth_getUncleByBlockNumberAndIndex",
+                r#"["latest","0x0"]"#.to_string(),
+                "null".to_string(),
+            ),
+            (
+                "eth_getWork",
+                "[]".to_string(),
+                format!(r#"["{0}","{0}","{0}"]"#, zero),
+            ),
+            (
+                "eth_submitWork",
+                format!(r#"["0x0000000000000000","{0}","{0}"]"#, zero),
+                "false".to_string(),
+            ),
+            (
+                "eth_submitHashrate",
+                format!(r#"["0x0","{}"]"#, zero),
+                "false".to_string(),
+            ),
+        ];
+        for (method, params, result) in cases.iter() {
+            assert_eq!(
+                call(method, params),
+                format!(r#"{{"jsonrpc":"2.0","result":{},"id":1}}"#, result)
+            );
+        }
+    }
+
+    #[test]
+    fn call_without_overrides() {
+        let request = r#"{"to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","input":"0x70a08231"}"#;
+        assert_eq!(
+            call("eth_call", &format!("[{}]", request)),
+            r#"{"jsonrpc":"2.0","result":"0x70a08231","id":1}"#
+        );
+        assert_eq!(
+            call("eth_call", &format!(r#"[{}, "latest"]"#, request)),
+            r#"{"jsonrpc":"2.0","result":"0x70a08231","id":1}"#
+        );
+        assert_eq!(
+            call("eth_call", &format!(r#"[{}, "latest", null]"#, request)),
+            r#"{"jsonrpc":"2.0","result":"0x70a08231","id":1}"#
+        );
+    }
+
+    #[test]
+    fn call_with_state_override() {
+        // Sent by Foundry's `cast call --override-code --override-state-diff`.
+        let params = r#"[
+            {
+                "from": "0x0000000000000000000000000000000000000000",
+                "to": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+                "input": "0x70a08231"
+            },
+            "latest",
+            {
+                "0x5fbdb2315678afecb367f032d93f642f64180aa3": {
+                    "code": "0x60016000526001601ff3",
+                    "stateDiff": {
+                        "0x0000000000000000000000000000000000000000000000000000000000000002": "0x00000000000000000000000000000000000000000000000000000000000003e8"
+                    }
+                }
+            }
+        ]"#;
+        assert_eq!(
+            call("eth_call", params),
+            r#"{"jsonrpc":"2.0","result":"0x60016000526001601ff300000000000000000000000000000000000000000000000000000000000003e8","id":1}"#
+        );
+    }
+
+    #[test]
+    fn call_with_block_overrides() {
+        let params = r#"[
+            {"to": "0x5fbdb2315678afecb367f032d93f642f64180aa3", "input": "0x"},
+            "latest",
+            null,
+            {"number": "0x10", "time": 1722000000}
+        ]"#;
+        assert_eq!(
+            call("eth_call", params),
+            r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000000000000000000000000000000010","id":1}"#
+        );
+    }
+
+    #[test]
+    fn estimate_gas_params() {
+        let request = r#"{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3"}"#;
+        assert_eq!(
+            call("eth_estimateGas", &format!("[{}]", request)),
+            r#"{"jsonrpc":"2.0","result":"0x5208","id":1}"#
+        );
+        assert_eq!(
+            call("eth_estimateGas", &format!(r#"[{}, "pending"]"#, request)),
+            r#"{"jsonrpc":"2.0","result":"0x5208","id":1}"#
+        );
+        // As sent by a bundler estimating a user operation of an unfunded
+        // sender.
+        assert_eq!(
+            call(
+                "eth_estimateGas",
+                &format!(
+                    r#"[{}, "latest", {{"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266":{{"balance":"0x56bc75e2d63100000"}}}}]"#,
+                    request
+                )
+            ),
+            r#"{"jsonrpc":"2.0","result":"0x5209","id":1}"#
+        );
+        assert_eq!(
+            call(
+                "eth_estimateGas",
+                &format!(
+                    r#"[{}, "latest", {{"0x5fbdb2315678afecb367f032d93f642f64180aa3":{{"state":{{}}}}}}]"#,
+                    request
+                )
+            ),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"state overrides are not supported"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn call_invalid_state_override() {
+        let params = r#"[
+            {"to": "0x5fbdb2315678afecb367f032d93f642f64180aa3"},
+            "latest",
+            {
+                "0x5fbdb2315678afecb367f032d93f642f64180aa3": {
+                    "state": {},
+                    "stateDiff": {}
+                }
+            }
+        ]"#;
+        assert_eq!(
+            call("eth_call", params),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid params: Invalid account override: state and stateDiff are mutually exclusive."},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn send_transaction_conflicting_input() {
+        assert_eq!(
+            call(
+                "eth_sendTransaction",
+                r#"[{"from":"0x9b2055d370f73ec7d8a03e965129118dc8f5bf83","data":"0xa9059cbb","input":"0x"}]"#
+            ),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid params: Both \"data\" and \"input\" are set and not equal. Please use \"input\" to pass transaction call data."},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn unstubbed_method_error() {
+        assert_eq!(
+            call(
+                "eth_getBalance",
+                r#"["0x9b2055d370f73ec7d8a03e965129118dc8f5bf83"]"#
+            ),
+            r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn read_only_handler() {
+        let mut io = IoHandler::new();
+        io.extend_with(MockEth(()).to_delegate());
+        let request = r#"{"jsonrpc":"2.0","method":"eth_accounts","params":[],"id":1}"#;
+        assert_eq!(
+            io.handle_request_sync(request).unwrap(),
+            r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"},"id":1}"#
+        );
+        assert_eq!(
+            call("eth_accounts", "[]"),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+    }
 }
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Eth bundle simulation rpc interface.
+
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{Bundle, CallStateOverride, EthCallResponse, StateContext};
+
+/// Eth bundle simulation rpc interface.
+#[rpc(server)]
+pub trait EthBundleApi {
+    /// Simulates the given bundles in order on top of the given state,
+    /// without producing traces nor committing any change, and returns the
+    /// result of each call of each bundle.
+    #[rpc(name = "eth_callMany")]
+    fn call_many(
+        &self,
+        _: Vec<Bundle>,
+        _: StateContext,
+        _: Option<CallStateOverride>,
+    ) -> BoxFuture<Result<Vec<Vec<EthCallResponse>>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::IoHandler;
+
+    struct MockEthBundle;
+
+    impl EthBundleApi for MockEthBundle {
+        // Echoes the call data, failing calls without recipient.
+        fn call_many(
+            &self,
+            bundles: Vec<Bundle>,
+            _: StateContext,
+            overrides: Option<CallStateOverride>,
+        ) -> BoxFuture<Result<Vec<Vec<EthCallResponse>>>> {
+            let res = bundles
+                .into_iter()
+                .map(|bundle| {
+                    bundle
+                        .transactions
+                        .into_iter()
+                        .map(|call| match call.to {
+                            Some(_) => EthCallResponse {
+                                value: call.data,
+                                error: None,
+                            },
+                            None => EthCallResponse {
+                                value: None,
+                                error: Some(format!("{} overrides", overrides.iter().len())),
+                            },
+                        })
+                        .collect()
+                })
+                .collect();
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+    }
+
+    fn call(params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockEthBundle.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"eth_callMany","params":{},"id":1}}"#,
+            params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn call_many_params() {
+        assert_eq!(
+            call(
+                r#"[[{"transactions":[{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","data":"0x06fdde03"},{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","data":"0x6080"}],"blockOverride":{"number":"0x11","time":1722000000}}],{"blockNumber":"0x10","transactionIndex":-1},{"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266":{"balance":"0xde0b6b3a7640000"}}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":[[{"value":"0x06fdde03"},{"error":"1 overrides"}]],"id":1}"#
+        );
+        // The state overrides are optional.
+        assert_eq!(
+            call(
+                r#"[[{"transactions":[{"to":"0x5fbdb2315678afecb367f032d93f642f64180aa3"}]},{"transactions":[]}],{"blockNumber":"latest"}]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":[[{}],[]],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(MockEthBundle.to_delegate(), &["eth_callMany"]);
+    }
+}
@@ -25,29 +25,131 @@ use jsonrpc_derive::rpc;
 use crate::types::{Filter, FilterChanges, Index, Log};
 
 /// Eth filters rpc api (polling).
+///
+/// Kept apart from `EthApi` so that implementers can back it with their own
+/// filter pool state. Log, block and pending transaction filters share a
+/// single id space: every filter id is a `U256` quantity.
 #[rpc(server)]
 pub trait EthFilterApi {
     /// Returns id of new filter.
     #[rpc(name = "eth_newFilter")]
     fn new_filter(&self, _: Filter) -> BoxFuture<Result<U256>>;
 
-    /// Returns id of new block filter.
+    /// Returns id of new block filter. Polling it with `eth_getFilterChanges`
+    /// yields the hashes of the blocks imported since the last poll.
     #[rpc(name = "eth_newBlockFilter")]
     fn new_block_filter(&self) -> BoxFuture<Result<U256>>;
 
-    /// Returns id of new block filter.
+    /// Returns id of new pending transaction filter. The optional flag asks
+    /// for full transaction bodies instead of hashes on poll.
     #[rpc(name = "eth_newPendingTransactionFilter")]
-    fn new_pending_transaction_filter(&self) -> BoxFuture<Result<U256>>;
+    fn new_pending_transaction_filter(&self, _: Option<bool>) -> BoxFuture<Result<U256>>;
 
     /// Returns filter changes since last poll.
     #[rpc(name = "eth_getFilterChanges")]
     fn filter_changes(&self, _: Index) -> BoxFuture<Result<FilterChanges>>;
 
     /// Returns all logs matching given filter (in a range 'from' - 'to').
+    ///
+    /// Only log filters have logs; any other filter id should be answered
+    /// with an error.
     #[rpc(name = "eth_getFilterLogs")]
     fn filter_logs(&self, _: Index) -> BoxFuture<Result<Vec<Log>>>;
 
-    /// Uninstalls filter.
+    /// Uninstalls filter. Returns false if the filter id was not found.
     #[rpc(name = "eth_uninstallFilter")]
     fn uninstall_filter(&self, _: Index) -> BoxFuture<Result<bool>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::{Error, IoHandler};
+
+    struct MockEthFilter;
+
+    impl EthFilterApi for MockEthFilter {
+        fn new_filter(&self, _: Filter) -> BoxFuture<Result<U256>> {
+            Box::pin(futures::future::ready(Ok(U256::one())))
+        }
+
+        fn new_block_filter(&self) -> BoxFuture<Result<U256>> {
+            Box::pin(futures::future::ready(Ok(U256::from(2))))
+        }
+
+        fn new_pending_transaction_filter(&self, full: Option<bool>) -> BoxFuture<Result<U256>> {
+            let id = if full.unwrap_or(false) { 4 } else { 3 };
+            Box::pin(futures::future::ready(Ok(U256::from(id))))
+        }
+
+        fn filter_changes(&self, _: Index) -> BoxFuture<Result<FilterChanges>> {
+            Box::pin(futures::future::ready(Ok(FilterChanges::Empty)))
+        }
+
+        fn filter_logs(&self, index: Index) -> BoxFuture<Result<Vec<Log>>> {
+            let res = match index.value() {
+                1 => Ok(vec![]),
+                _ => Err(Error::invalid_params("Filter id is not a log filter")),
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn uninstall_filter(&self, index: Index) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(index.value() == 1)))
+        }
+    }
+
+    fn io() -> IoHandler {
+        let mut io = IoHandler::new();
+        io.extend_with(MockEthFilter.to_delegate());
+        io
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io().handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn filter_ids_are_quantities() {
+        assert_eq!(
+            call("eth_newFilter", r#"[{}]"#),
+            r#"{"jsonrpc":"2.0","result":"0x1","id":1}"#
+        );
+        assert_eq!(
+            call("eth_newBlockFilter", "[]"),
+            r#"{"jsonrpc":"2.0","result":"0x2","id":1}"#
+        );
+    }
+
+    #[test]
+    fn new_pending_transaction_filter_params() {
+        assert_eq!(
+            call("eth_newPendingTransactionFilter", "[]"),
+            r#"{"jsonrpc":"2.0","result":"0x3","id":1}"#
+        );
+        assert_eq!(
+            call("eth_newPendingTransactionFilter", "[true]"),
+            r#"{"jsonrpc":"2.0","result":"0x4","id":1}"#
+        );
+    }
+
+    #[test]
+    fn filter_logs_of_log_filter() {
+        assert_eq!(
+            call("eth_getFilterLogs", r#"["0x1"]"#),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn filter_logs_of_non_log_filter() {
+        assert_eq!(
+            call("eth_getFilterLogs", r#"["0x2"]"#),
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Filter id is not a log filter"},"id":1}"#
+        );
+    }
+}
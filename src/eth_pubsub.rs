@@ -27,7 +27,7 @@ use crate::types::pubsub;
 /// Eth PUB-SUB rpc interface.
 #[rpc(server)]
 pub trait EthPubSubApi {
-    /// RPC Metadata
+    /// RPC Metadata, usually `types::pubsub::Metadata`.
     type Metadata;
 
     /// Subscribe to Eth subscription.
@@ -40,7 +40,8 @@ pub trait EthPubSubApi {
         _: Option<pubsub::Params>,
     );
 
-    /// Unsubscribe from existing Eth subscription.
+    /// Unsubscribe from existing Eth subscription. Returns false if the
+    /// subscription id is unknown.
     #[pubsub(
         subscription = "eth_subscription",
         unsubscribe,
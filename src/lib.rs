@@ -16,16 +16,55 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+mod admin;
+mod debug;
+#[cfg(feature = "dev")]
+mod dev;
 mod eth;
+mod eth_bundle;
 mod eth_filter;
 mod eth_pubsub;
+mod miner;
 mod net;
+mod personal;
+mod rpc;
+mod system;
+mod trace;
+mod txpool;
 mod web3;
 
+pub mod errors;
 pub mod types;
 
-pub use eth::EthApi;
+pub use admin::AdminApi;
+pub use debug::DebugApi;
+#[cfg(feature = "dev")]
+pub use dev::DevApi;
+pub use eth::{EthApi, EthExtApi, EthFullApi, EthSigningApi};
+pub use eth_bundle::EthBundleApi;
 pub use eth_filter::EthFilterApi;
 pub use eth_pubsub::EthPubSubApi;
+pub use miner::MinerApi;
 pub use net::NetApi;
+pub use personal::PersonalApi;
+pub use rpc::{rpc_modules, RpcApi, RPC_MODULES};
+pub use system::SystemApi;
+pub use trace::TraceApi;
+pub use txpool::TxPoolApi;
 pub use web3::Web3Api;
+
+/// Asserts that the given delegate registers exactly the expected methods,
+/// all in a namespace listed in `RPC_MODULES`.
+#[cfg(test)]
+fn assert_method_names<T>(delegate: jsonrpc_core::IoDelegate<T, ()>, expected: &[&str])
+where
+    T: Send + Sync + 'static,
+{
+    let mut names: Vec<String> = delegate.into_iter().map(|(name, _)| name).collect();
+    names.sort();
+    assert_eq!(names, expected);
+    for name in names {
+        let module = name.split('_').next().unwrap();
+        assert!(RPC_MODULES.contains(&module), "{} not in RPC_MODULES", name);
+    }
+}
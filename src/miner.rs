@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Miner rpc interface.
+
+use ethereum_types::{H160, U256};
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{Bytes, Quantity};
+
+/// Miner rpc interface.
+#[rpc(server)]
+pub trait MinerApi {
+    /// Sets the address receiving the rewards of mined blocks.
+    #[rpc(name = "miner_setEtherbase")]
+    fn set_etherbase(&self, _: H160) -> BoxFuture<Result<bool>>;
+
+    /// Sets the minimal gas price of transactions accepted in mined blocks.
+    #[rpc(name = "miner_setGasPrice")]
+    fn set_gas_price(&self, _: U256) -> BoxFuture<Result<bool>>;
+
+    /// Sets the gas limit mined blocks target.
+    #[rpc(name = "miner_setGasLimit")]
+    fn set_gas_limit(&self, _: U256) -> BoxFuture<Result<bool>>;
+
+    /// Sets the extra data of mined blocks.
+    #[rpc(name = "miner_setExtra")]
+    fn set_extra(&self, _: Bytes) -> BoxFuture<Result<bool>>;
+
+    /// Starts mining with the given amount of threads, or the
+    /// implementation's default if omitted.
+    #[rpc(name = "miner_start")]
+    fn start(&self, _: Option<Quantity>) -> BoxFuture<Result<()>>;
+
+    /// Stops mining.
+    #[rpc(name = "miner_stop")]
+    fn stop(&self) -> BoxFuture<Result<()>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::{Error, IoHandler};
+
+    struct MockMiner;
+
+    impl MinerApi for MockMiner {
+        fn set_etherbase(&self, _: H160) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn set_gas_price(&self, _: U256) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn set_gas_limit(&self, _: U256) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn set_extra(&self, extra: Bytes) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(extra.0.len() <= 32)))
+        }
+
+        // Rejects more than 8 threads.
+        fn start(&self, threads: Option<Quantity>) -> BoxFuture<Result<()>> {
+            let res = match threads {
+                Some(threads) if threads.value() > U256::from(8) => {
+                    Err(Error::invalid_params("Too many threads"))
+                }
+                _ => Ok(()),
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn stop(&self) -> BoxFuture<Result<()>> {
+            Box::pin(futures::future::ready(Ok(())))
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockMiner.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn start_params() {
+        for params in ["[]", "[4]", r#"["0x4"]"#] {
+            assert_eq!(
+                call("miner_start", params),
+                r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+            );
+        }
+        for params in ["[16]", r#"["0x10"]"#] {
+            assert!(call("miner_start", params).contains(r#""code":-32602"#));
+        }
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockMiner.to_delegate(),
+            &[
+                "miner_setEtherbase",
+                "miner_setExtra",
+                "miner_setGasLimit",
+                "miner_setGasPrice",
+                "miner_start",
+                "miner_stop",
+            ],
+        );
+    }
+}
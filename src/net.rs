@@ -20,14 +20,13 @@
 use crate::types::PeerCount;
 use jsonrpc_core::{BoxFuture, Result};
 use jsonrpc_derive::rpc;
-use serde_json::Value;
 
 /// Net rpc interface.
 #[rpc(server)]
 pub trait NetApi {
-    /// Returns protocol version.
+    /// Returns the network id as a decimal string.
     #[rpc(name = "net_version")]
-    fn version(&self) -> BoxFuture<Result<Value>>;
+    fn version(&self) -> BoxFuture<Result<String>>;
 
     /// Returns number of peers connected to node.
     #[rpc(name = "net_peerCount")]
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Personal rpc interface.
+
+use ethereum_types::{H160, H256};
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{Bytes, Quantity, TransactionRequest};
+
+/// Personal rpc interface.
+#[rpc(server)]
+pub trait PersonalApi {
+    /// Returns the accounts managed by the node.
+    #[rpc(name = "personal_listAccounts")]
+    fn accounts(&self) -> BoxFuture<Result<Vec<H160>>>;
+
+    /// Creates a new account protected by the given passphrase, returning
+    /// its address.
+    #[rpc(name = "personal_newAccount")]
+    fn new_account(&self, _: String) -> BoxFuture<Result<H160>>;
+
+    /// Unlocks the given account with its passphrase for the given amount of
+    /// seconds, or the implementation's default duration if omitted.
+    #[rpc(name = "personal_unlockAccount")]
+    fn unlock_account(&self, _: H160, _: String, _: Option<Quantity>) -> BoxFuture<Result<bool>>;
+
+    /// Locks the given account.
+    #[rpc(name = "personal_lockAccount")]
+    fn lock_account(&self, _: H160) -> BoxFuture<Result<bool>>;
+
+    /// Sends the given transaction, signed with the sender account unlocked
+    /// by the given passphrase for this call only.
+    #[rpc(name = "personal_sendTransaction")]
+    fn send_transaction(&self, _: TransactionRequest, _: String) -> BoxFuture<Result<H256>>;
+
+    /// Signs the given message with the given account and passphrase,
+    /// returning the 65-byte signature. The message gets the EIP-191 prefix
+    /// applied as in `eth_sign`.
+    #[rpc(name = "personal_sign")]
+    fn sign(&self, _: Bytes, _: H160, _: String) -> BoxFuture<Result<Bytes>>;
+
+    /// Returns the address of the account that signed the given message with
+    /// the given signature, as produced by `personal_sign`.
+    #[rpc(name = "personal_ecRecover")]
+    fn ec_recover(&self, _: Bytes, _: Bytes) -> BoxFuture<Result<H160>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use jsonrpc_core::IoHandler;
+
+    struct MockPersonal;
+
+    impl PersonalApi for MockPersonal {
+        fn accounts(&self) -> BoxFuture<Result<Vec<H160>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn new_account(&self, _: String) -> BoxFuture<Result<H160>> {
+            Box::pin(futures::future::ready(Ok(H160::zero())))
+        }
+
+        // Unlocks only for exactly five minutes.
+        fn unlock_account(
+            &self,
+            _: H160,
+            _: String,
+            duration: Option<Quantity>,
+        ) -> BoxFuture<Result<bool>> {
+            let res = duration.map(|d| d.value()) == Some(U256::from(300));
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn lock_account(&self, _: H160) -> BoxFuture<Result<bool>> {
+            Box::pin(futures::future::ready(Ok(true)))
+        }
+
+        fn send_transaction(&self, _: TransactionRequest, _: String) -> BoxFuture<Result<H256>> {
+            Box::pin(futures::future::ready(Ok(H256::zero())))
+        }
+
+        fn sign(&self, message: Bytes, _: H160, _: String) -> BoxFuture<Result<Bytes>> {
+            Box::pin(futures::future::ready(Ok(message)))
+        }
+
+        fn ec_recover(&self, _: Bytes, _: Bytes) -> BoxFuture<Result<H160>> {
+            Box::pin(futures::future::ready(Ok(H160::zero())))
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockPersonal.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn unlock_account_duration() {
+        let account = "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83";
+        // As sent by web3.js and by the geth console.
+        for duration in [r#""0x12c""#, "300"] {
+            assert_eq!(
+                call(
+                    "personal_unlockAccount",
+                    &format!(r#"["{}","secret",{}]"#, account, duration)
+                ),
+                r#"{"jsonrpc":"2.0","result":true,"id":1}"#
+            );
+        }
+        assert_eq!(
+            call(
+                "personal_unlockAccount",
+                &format!(r#"["{}","secret"]"#, account)
+            ),
+            r#"{"jsonrpc":"2.0","result":false,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockPersonal.to_delegate(),
+            &[
+                "personal_ecRecover",
+                "personal_listAccounts",
+                "personal_lockAccount",
+                "personal_newAccount",
+                "personal_sendTransaction",
+                "personal_sign",
+                "personal_unlockAccount",
+            ],
+        );
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Rpc discovery interface.
+
+use std::collections::BTreeMap;
+
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+/// Namespaces of the rpc interfaces defined by this crate.
+pub const RPC_MODULES: &[&str] = &[
+    "admin",
+    #[cfg(feature = "dev")]
+    "anvil",
+    "debug",
+    "eth",
+    #[cfg(feature = "dev")]
+    "evm",
+    #[cfg(feature = "dev")]
+    "hardhat",
+    "miner",
+    "net",
+    "personal",
+    "rpc",
+    "system",
+    "trace",
+    "txpool",
+    "web3",
+];
+
+/// Returns the namespaces of the rpc interfaces defined by this crate, each
+/// with version `1.0`, as expected from `rpc_modules`.
+pub fn rpc_modules() -> BTreeMap<String, String> {
+    RPC_MODULES
+        .iter()
+        .map(|module| (module.to_string(), "1.0".to_string()))
+        .collect()
+}
+
+/// Rpc discovery interface.
+#[rpc(server)]
+pub trait RpcApi {
+    /// Returns the namespaces offered by the node, with their version.
+    #[rpc(name = "rpc_modules")]
+    fn modules(&self) -> BoxFuture<Result<BTreeMap<String, String>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::IoHandler;
+    use std::collections::BTreeSet;
+    use std::fs;
+
+    struct MockRpc;
+
+    impl RpcApi for MockRpc {
+        fn modules(&self) -> BoxFuture<Result<BTreeMap<String, String>>> {
+            Box::pin(futures::future::ready(Ok(rpc_modules())))
+        }
+    }
+
+    #[test]
+    fn modules_result() {
+        let mut io = IoHandler::new();
+        io.extend_with(MockRpc.to_delegate());
+        let request = r#"{"jsonrpc":"2.0","method":"rpc_modules","params":[],"id":1}"#;
+        let (anvil, evm) = if cfg!(feature = "dev") {
+            (r#""anvil":"1.0","#, r#""evm":"1.0","hardhat":"1.0","#)
+        } else {
+            ("", "")
+        };
+        assert_eq!(
+            io.handle_request_sync(request).unwrap(),
+            format!(
+                r#"{{"jsonrpc":"2.0","result":{{"admin":"1.0",{}"debug":"1.0","eth":"1.0",{}"miner":"1.0","net":"1.0","personal":"1.0","rpc":"1.0","system":"1.0","trace":"1.0","txpool":"1.0","web3":"1.0"}},"id":1}}"#,
+                anvil, evm
+            )
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(MockRpc.to_delegate(), &["rpc_modules"]);
+    }
+
+    // Namespaces of the method names and aliases declared by the rpc
+    // interfaces in `src`, so that a new interface can't be left out of
+    // `RPC_MODULES`.
+    fn declared_namespaces() -> BTreeSet<String> {
+        let mut namespaces = BTreeSet::new();
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension() != Some("rs".as_ref())
+                || (path.ends_with("dev.rs") && !cfg!(feature = "dev"))
+            {
+                continue;
+            }
+            let source = fs::read_to_string(&path).unwrap();
+            for line in source.lines() {
+                // The `name` of `#[rpc]` and `#[pubsub]`, not serde's `rename`.
+                let names = match line.split_once("name = \"") {
+                    Some((attr, names)) if !attr.ends_with("re") => names,
+                    _ => continue,
+                };
+                for name in names.split('"').step_by(2) {
+                    if let Some((namespace, _)) = name.split_once('_') {
+                        namespaces.insert(namespace.to_string());
+                    }
+                }
+            }
+        }
+        namespaces
+    }
+
+    #[test]
+    fn modules_match_declared_namespaces() {
+        let modules: BTreeSet<String> = RPC_MODULES.iter().map(|m| m.to_string()).collect();
+        assert_eq!(modules, declared_namespaces());
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! System rpc interface.
+
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{Health, SyncInfo};
+
+/// System rpc interface.
+#[rpc(server)]
+pub trait SystemApi {
+    /// Returns the health of the node, for readiness probes.
+    #[rpc(name = "system_health")]
+    fn health(&self) -> BoxFuture<Result<Health>>;
+
+    /// Returns the block range of the ongoing or last sync.
+    #[rpc(name = "system_syncState")]
+    fn sync_state(&self) -> BoxFuture<Result<SyncInfo>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use jsonrpc_core::IoHandler;
+
+    struct MockSystem;
+
+    impl SystemApi for MockSystem {
+        fn health(&self) -> BoxFuture<Result<Health>> {
+            let res = Health {
+                peers: 0,
+                is_syncing: true,
+                should_have_peers: true,
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn sync_state(&self) -> BoxFuture<Result<SyncInfo>> {
+            let res = SyncInfo {
+                starting_block: U256::zero(),
+                current_block: U256::from(0x10),
+                highest_block: U256::from(0x20),
+                ..Default::default()
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+    }
+
+    fn call(method: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockSystem.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":[],"id":1}}"#,
+            method
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn health_result() {
+        assert_eq!(
+            call("system_health"),
+            r#"{"jsonrpc":"2.0","result":{"isSyncing":true,"peers":0,"shouldHavePeers":true},"id":1}"#
+        );
+        assert_eq!(
+            call("system_syncState"),
+            r#"{"jsonrpc":"2.0","result":{"currentBlock":"0x10","highestBlock":"0x20","startingBlock":"0x0","warpChunksAmount":null,"warpChunksProcessed":null},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockSystem.to_delegate(),
+            &["system_health", "system_syncState"],
+        );
+    }
+}
@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Trace rpc interface.
+
+use ethereum_types::H256;
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{
+    BlockNumber, Bytes, CallRequest, Index, LocalizedTrace, TraceFilter, TraceResults,
+    TraceResultsWithTransactionHash, TraceType,
+};
+
+/// Trace rpc interface.
+#[rpc(server)]
+pub trait TraceApi {
+    /// Returns the traces of the transaction with given hash, or `None` if
+    /// the transaction is unknown.
+    #[rpc(name = "trace_transaction")]
+    fn transaction_traces(&self, _: H256) -> BoxFuture<Result<Option<Vec<LocalizedTrace>>>>;
+
+    /// Returns the traces of every transaction of the given block, ordered by
+    /// transaction position then trace address, followed by the reward
+    /// traces.
+    #[rpc(name = "trace_block")]
+    fn block_traces(&self, _: BlockNumber) -> BoxFuture<Result<Vec<LocalizedTrace>>>;
+
+    /// Returns the traces matching the given filter.
+    #[rpc(name = "trace_filter")]
+    fn filter(&self, _: TraceFilter) -> BoxFuture<Result<Vec<LocalizedTrace>>>;
+
+    /// Executes the given call and returns the requested traces of it.
+    #[rpc(name = "trace_call")]
+    fn call(
+        &self,
+        _: CallRequest,
+        _: Vec<TraceType>,
+        _: Option<BlockNumber>,
+    ) -> BoxFuture<Result<TraceResults>>;
+
+    /// Executes the given calls sequentially on top of the given block, each
+    /// call seeing the state changes of the previous ones, and returns the
+    /// requested traces of each.
+    #[rpc(name = "trace_callMany")]
+    fn call_many(
+        &self,
+        _: Vec<(CallRequest, Vec<TraceType>)>,
+        _: Option<BlockNumber>,
+    ) -> BoxFuture<Result<Vec<TraceResults>>>;
+
+    /// Executes the given signed, RLP-encoded transaction and returns the
+    /// requested traces of it.
+    #[rpc(name = "trace_rawTransaction")]
+    fn raw_transaction(&self, _: Bytes, _: Vec<TraceType>) -> BoxFuture<Result<TraceResults>>;
+
+    /// Re-executes the transaction with given hash and returns the requested
+    /// traces of it.
+    #[rpc(name = "trace_replayTransaction")]
+    fn replay_transaction(&self, _: H256, _: Vec<TraceType>) -> BoxFuture<Result<TraceResults>>;
+
+    /// Re-executes every transaction of the given block and returns the
+    /// requested traces of each, annotated with the transaction hash.
+    #[rpc(name = "trace_replayBlockTransactions")]
+    fn replay_block_transactions(
+        &self,
+        _: BlockNumber,
+        _: Vec<TraceType>,
+    ) -> BoxFuture<Result<Vec<TraceResultsWithTransactionHash>>>;
+
+    /// Returns the trace at the given trace address of the transaction with
+    /// given hash, or `None` if there is no such trace.
+    #[rpc(name = "trace_get")]
+    fn trace(&self, _: H256, _: Vec<Index>) -> BoxFuture<Result<Option<LocalizedTrace>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Action, Call, CallType, Res, StateDiff};
+    use ethereum_types::{H160, U256};
+    use jsonrpc_core::{Error, IoHandler};
+
+    struct MockTrace;
+
+    impl TraceApi for MockTrace {
+        fn transaction_traces(&self, _: H256) -> BoxFuture<Result<Option<Vec<LocalizedTrace>>>> {
+            Box::pin(futures::future::ready(Ok(None)))
+        }
+
+        fn block_traces(&self, _: BlockNumber) -> BoxFuture<Result<Vec<LocalizedTrace>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn filter(&self, _: TraceFilter) -> BoxFuture<Result<Vec<LocalizedTrace>>> {
+            Box::pin(futures::future::ready(Ok(vec![])))
+        }
+
+        fn call(
+            &self,
+            request: CallRequest,
+            types: Vec<TraceType>,
+            _: Option<BlockNumber>,
+        ) -> BoxFuture<Result<TraceResults>> {
+            Box::pin(futures::future::ready(Ok(results(request, types))))
+        }
+
+        fn call_many(
+            &self,
+            requests: Vec<(CallRequest, Vec<TraceType>)>,
+            _: Option<BlockNumber>,
+        ) -> BoxFuture<Result<Vec<TraceResults>>> {
+            let res = requests
+                .into_iter()
+                .map(|(request, types)| results(request, types))
+                .collect();
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn raw_transaction(
+            &self,
+            raw: Bytes,
+            _: Vec<TraceType>,
+        ) -> BoxFuture<Result<TraceResults>> {
+            let res = match raw.into_vec().first() {
+                Some(0xc0..=0xff) => Ok(serde_json::from_str(RAW_TRANSACTION_RESULTS).unwrap()),
+                _ => Err(Error::invalid_params("Invalid RLP.")),
+            };
+            Box::pin(futures::future::ready(res))
+        }
+
+        fn replay_transaction(
+            &self,
+            _: H256,
+            types: Vec<TraceType>,
+        ) -> BoxFuture<Result<TraceResults>> {
+            Box::pin(futures::future::ready(Ok(results(
+                CallRequest::default(),
+                types,
+            ))))
+        }
+
+        fn replay_block_transactions(
+            &self,
+            number: BlockNumber,
+            types: Vec<TraceType>,
+        ) -> BoxFuture<Result<Vec<TraceResultsWithTransactionHash>>> {
+            let res = match number {
+                BlockNumber::Earliest => vec![],
+                _ => vec![TraceResultsWithTransactionHash {
+                    results: results(CallRequest::default(), types),
+                    transaction_hash: H256::repeat_byte(0x11),
+                }],
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+
+        fn trace(&self, hash: H256, path: Vec<Index>) -> BoxFuture<Result<Option<LocalizedTrace>>> {
+            let trace_address: Vec<usize> = path.iter().map(Index::value).collect();
+            let res = match trace_address.iter().all(|&i| i < 3) {
+                true => Some(LocalizedTrace {
+                    action: Action::Call(Call {
+                        from: H160::zero(),
+                        to: H160::zero(),
+                        value: U256::zero(),
+                        gas: U256::zero(),
+                        input: Bytes::default(),
+                        call_type: CallType::Call,
+                    }),
+                    result: Res::None,
+                    trace_address,
+                    subtraces: 0,
+                    transaction_position: Some(0),
+                    transaction_hash: Some(hash),
+                    block_number: 1,
+                    block_hash: H256::zero(),
+                }),
+                false => None,
+            };
+            Box::pin(futures::future::ready(Ok(res)))
+        }
+    }
+
+    const RAW_TRANSACTION_RESULTS: &str = r#"{"output":"0x","stateDiff":null,"trace":[{"action":{"callType":"call","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","gas":"0x0","input":"0x","to":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","value":"0xde0b6b3a7640000"},"result":{"gasUsed":"0x0","output":"0x"},"subtraces":0,"traceAddress":[],"type":"call"}],"vmTrace":null}"#;
+
+    // Echoes the call data as output and marks the requested state diff.
+    fn results(request: CallRequest, types: Vec<TraceType>) -> TraceResults {
+        TraceResults {
+            output: request.data.unwrap_or_default(),
+            trace: vec![],
+            vm_trace: None,
+            state_diff: types
+                .contains(&TraceType::StateDiff)
+                .then(StateDiff::default),
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockTrace.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn call_many_params() {
+        assert_eq!(
+            call(
+                "trace_callMany",
+                r#"[[[{"from":"0x407d73d8a49eeb85d32cf465507dd71d507100c1","to":"0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b","value":"0x186a0","data":"0x01"},["trace"]],[{"from":"0x407d73d8a49eeb85d32cf465507dd71d507100c1","to":"0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b","value":"0x186a0","data":"0x02"},["trace","stateDiff"]]],"latest"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":[{"output":"0x01","stateDiff":null,"trace":[],"vmTrace":null},{"output":"0x02","stateDiff":{},"trace":[],"vmTrace":null}],"id":1}"#
+        );
+        // The base block is optional.
+        assert_eq!(
+            call(
+                "trace_callMany",
+                r#"[[[{"to":"0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b"},[]]]]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":[{"output":"0x","stateDiff":null,"trace":[],"vmTrace":null}],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn call_many_rejects_flat_pairs() {
+        let res = call(
+            "trace_callMany",
+            r#"[[{"to":"0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b"},["trace"]]]"#,
+        );
+        assert!(res.contains(r#""code":-32602"#), "{}", res);
+    }
+
+    #[test]
+    fn raw_transaction_result() {
+        assert_eq!(
+            call(
+                "trace_rawTransaction",
+                r#"["0xf86c808504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",["trace"]]"#
+            ),
+            format!(
+                r#"{{"jsonrpc":"2.0","result":{},"id":1}}"#,
+                RAW_TRANSACTION_RESULTS
+            )
+        );
+        let res = call("trace_rawTransaction", r#"["0x01",["trace"]]"#);
+        assert!(res.contains(r#""code":-32602"#), "{}", res);
+    }
+
+    #[test]
+    fn replay_block_transactions_params() {
+        assert_eq!(
+            call("trace_replayBlockTransactions", r#"["latest",["trace"]]"#),
+            r#"{"jsonrpc":"2.0","result":[{"output":"0x","stateDiff":null,"trace":[],"transactionHash":"0x1111111111111111111111111111111111111111111111111111111111111111","vmTrace":null}],"id":1}"#
+        );
+        assert_eq!(
+            call("trace_replayBlockTransactions", r#"["0x0",["trace"]]"#),
+            r#"{"jsonrpc":"2.0","result":[{"output":"0x","stateDiff":null,"trace":[],"transactionHash":"0x1111111111111111111111111111111111111111111111111111111111111111","vmTrace":null}],"id":1}"#
+        );
+        assert_eq!(
+            call("trace_replayBlockTransactions", r#"["earliest",["trace"]]"#),
+            r#"{"jsonrpc":"2.0","result":[],"id":1}"#
+        );
+    }
+
+    #[test]
+    fn trace_get_params() {
+        let hash = "0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3";
+        let trace = |address: &str| {
+            format!(
+                r#"{{"jsonrpc":"2.0","result":{{"action":{{"callType":"call","from":"0x0000000000000000000000000000000000000000","gas":"0x0","input":"0x","to":"0x0000000000000000000000000000000000000000","value":"0x0"}},"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000000","blockNumber":1,"result":null,"subtraces":0,"traceAddress":{},"transactionHash":"{}","transactionPosition":0,"type":"call"}},"id":1}}"#,
+                address, hash
+            )
+        };
+        assert_eq!(
+            call("trace_get", &format!(r#"["{}",["0x0"]]"#, hash)),
+            trace("[0]")
+        );
+        assert_eq!(
+            call("trace_get", &format!(r#"["{}",["0x2","0x1"]]"#, hash)),
+            trace("[2,1]")
+        );
+        assert_eq!(
+            call("trace_get", &format!(r#"["{}",["0x5"]]"#, hash)),
+            r#"{"jsonrpc":"2.0","result":null,"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockTrace.to_delegate(),
+            &[
+                "trace_block",
+                "trace_call",
+                "trace_callMany",
+                "trace_filter",
+                "trace_get",
+                "trace_rawTransaction",
+                "trace_replayBlockTransactions",
+                "trace_replayTransaction",
+                "trace_transaction",
+            ],
+        );
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! TxPool rpc interface.
+
+use ethereum_types::H160;
+use jsonrpc_core::{BoxFuture, Result};
+use jsonrpc_derive::rpc;
+
+use crate::types::{TxPoolContent, TxPoolContentFrom, TxPoolInspect, TxPoolStatus};
+
+/// TxPool rpc interface.
+#[rpc(server)]
+pub trait TxPoolApi {
+    /// Returns the number of pending and queued transactions in the pool.
+    #[rpc(name = "txpool_status")]
+    fn status(&self) -> BoxFuture<Result<TxPoolStatus>>;
+
+    /// Returns the pending and queued transactions of every sender in the
+    /// pool, keyed by nonce.
+    #[rpc(name = "txpool_content")]
+    fn content(&self) -> BoxFuture<Result<TxPoolContent>>;
+
+    /// Returns the pending and queued transactions of the given sender,
+    /// keyed by nonce.
+    #[rpc(name = "txpool_contentFrom")]
+    fn content_from(&self, _: H160) -> BoxFuture<Result<TxPoolContentFrom>>;
+
+    /// Returns a textual summary of every pool transaction, in the same
+    /// layout as `txpool_content`.
+    #[rpc(name = "txpool_inspect")]
+    fn inspect(&self) -> BoxFuture<Result<TxPoolInspect>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use jsonrpc_core::IoHandler;
+    use std::collections::BTreeMap;
+
+    struct MockTxPool;
+
+    // An empty pool.
+    impl TxPoolApi for MockTxPool {
+        fn status(&self) -> BoxFuture<Result<TxPoolStatus>> {
+            Box::pin(futures::future::ready(Ok(TxPoolStatus {
+                pending: U256::zero(),
+                queued: U256::zero(),
+            })))
+        }
+
+        fn content(&self) -> BoxFuture<Result<TxPoolContent>> {
+            Box::pin(futures::future::ready(Ok(TxPoolContent {
+                pending: BTreeMap::new(),
+                queued: BTreeMap::new(),
+            })))
+        }
+
+        fn content_from(&self, _: H160) -> BoxFuture<Result<TxPoolContentFrom>> {
+            Box::pin(futures::future::ready(Ok(TxPoolContentFrom::default())))
+        }
+
+        fn inspect(&self) -> BoxFuture<Result<TxPoolInspect>> {
+            Box::pin(futures::future::ready(Ok(TxPoolInspect {
+                pending: BTreeMap::new(),
+                queued: BTreeMap::new(),
+            })))
+        }
+    }
+
+    fn call(method: &str, params: &str) -> String {
+        let mut io = IoHandler::new();
+        io.extend_with(MockTxPool.to_delegate());
+        let request = format!(
+            r#"{{"jsonrpc":"2.0","method":"{}","params":{},"id":1}}"#,
+            method, params
+        );
+        io.handle_request_sync(&request).unwrap()
+    }
+
+    #[test]
+    fn content_from_empty_pool() {
+        assert_eq!(
+            call(
+                "txpool_contentFrom",
+                r#"["0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c"]"#
+            ),
+            r#"{"jsonrpc":"2.0","result":{"pending":{},"queued":{}},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn status_empty_pool() {
+        assert_eq!(
+            call("txpool_status", "[]"),
+            r#"{"jsonrpc":"2.0","result":{"pending":"0x0","queued":"0x0"},"id":1}"#
+        );
+    }
+
+    #[test]
+    fn method_names() {
+        crate::assert_method_names(
+            MockTxPool.to_delegate(),
+            &[
+                "txpool_content",
+                "txpool_contentFrom",
+                "txpool_inspect",
+                "txpool_status",
+            ],
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Access list types (EIP-2930).
+
+use ethereum_types::{H160, H256, U256};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_value, Value};
+
+/// Access list of a transaction.
+pub type AccessList = Vec<AccessListItem>;
+
+/// Addresses and storage keys a transaction plans to access.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListItem {
+    /// Accessed address
+    pub address: H160,
+    /// Accessed storage keys
+    pub storage_keys: Vec<H256>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAccessListItem {
+    address: H160,
+    storage_keys: Vec<Value>,
+}
+
+impl<'a> Deserialize<'a> for AccessListItem {
+    fn deserialize<D>(deserializer: D) -> Result<AccessListItem, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let raw = RawAccessListItem::deserialize(deserializer)?;
+        let storage_keys = raw
+            .storage_keys
+            .into_iter()
+            .enumerate()
+            .map(|(i, key)| {
+                from_value(key.clone()).map_err(|err| {
+                    D::Error::custom(format!(
+                        "Invalid storage key {} at index {} for {:?}: {}",
+                        key, i, raw.address, err
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(AccessListItem {
+            address: raw.address,
+            storage_keys,
+        })
+    }
+}
+
+/// Response of `eth_createAccessList`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListResult {
+    /// Access list of the call, possibly partial if it failed
+    pub access_list: AccessList,
+    /// Gas used by the call with the access list
+    pub gas_used: U256,
+    /// Error of the call, if it failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn access_list_result_serde() {
+        let s = r#"{"accessList":[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081","0x0000000000000000000000000000000000000000000000000000000000000003"]}],"gasUsed":"0x125f8"}"#;
+        let result: AccessListResult = serde_json::from_str(s).unwrap();
+        assert_eq!(result.access_list[0].storage_keys.len(), 2);
+        assert_eq!(result.error, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+    }
+
+    #[test]
+    fn access_list_result_reverted_serde() {
+        // geth returns the partial list with the error rather than failing.
+        let s = r#"{"accessList":[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":[]}],"error":"execution reverted","gasUsed":"0x5b8d"}"#;
+        let result: AccessListResult = serde_json::from_str(s).unwrap();
+        assert_eq!(result.error.as_deref(), Some("execution reverted"));
+        assert!(result.access_list[0].storage_keys.is_empty());
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+    }
+
+    #[test]
+    fn access_list_serde() {
+        let s = r#"[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081"]},{"address":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","storageKeys":[]}]"#;
+        let list: AccessList = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            list,
+            vec![
+                AccessListItem {
+                    address: "0xa02457e5dfd32bda5fc7e1f1b008aa5979568150"
+                        .parse()
+                        .unwrap(),
+                    storage_keys: vec![H256::from_low_u64_be(0x81)],
+                },
+                AccessListItem {
+                    address: "0xd46e8dd67c5d32be8058bb8eb970870f07244567"
+                        .parse()
+                        .unwrap(),
+                    storage_keys: vec![],
+                },
+            ]
+        );
+        assert_eq!(serde_json::to_string(&list).unwrap(), s);
+
+        let empty: AccessList = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn access_list_invalid_storage_key() {
+        let s = r#"[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081","0x81"]}]"#;
+        let err = serde_json::from_str::<AccessList>(s).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with(r#"Invalid storage key "0x81" at index 1 for 0xa02457e5dfd32bda5fc7e1f1b008aa5979568150: "#));
+    }
+}
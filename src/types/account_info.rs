@@ -19,8 +19,8 @@
 //! Return types for RPC calls
 
 use crate::types::Bytes;
-use ethereum_types::{Address, Public, H160, H256, U256};
-use serde::Serialize;
+use ethereum_types::{Address, Public, H160, H256, U256, U64};
+use serde::{Deserialize, Serialize};
 
 /// Account information.
 #[derive(Debug, Default, Clone, PartialEq, Serialize)]
@@ -30,27 +30,54 @@ pub struct AccountInfo {
 }
 
 /// Data structure with proof for one single storage-entry
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageProof {
-    pub key: U256,
+    /// Storage key, as requested
+    pub key: H256,
+    /// Storage value
     pub value: U256,
+    /// Merkle proof of the value, from the storage root
     pub proof: Vec<Bytes>,
 }
 
-/// Account information.
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+/// Account with Merkle proofs of its state and storage (EIP-1186).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct EthAccount {
+pub struct EIP1186ProofResponse {
+    /// Address
     pub address: H160,
+    /// Balance
     pub balance: U256,
-    pub nonce: U256,
+    /// Code hash
     pub code_hash: H256,
+    /// Nonce
+    pub nonce: U256,
+    /// Storage root
     pub storage_hash: H256,
+    /// Merkle proof of the account, from the state root
     pub account_proof: Vec<Bytes>,
+    /// Proofs of the requested storage entries
     pub storage_proof: Vec<StorageProof>,
 }
 
+/// Account information.
+pub type EthAccount = EIP1186ProofResponse;
+
+/// Account state (used by `eth_getAccount`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    /// Balance
+    pub balance: U256,
+    /// Nonce
+    pub nonce: U64,
+    /// Code hash, the hash of empty code for accounts without code
+    pub code_hash: H256,
+    /// Storage root, the empty trie root for accounts without storage
+    pub storage_root: H256,
+}
+
 /// Extended account information (used by `parity_allAccountInfo`).
 #[derive(Debug, Default, Clone, PartialEq, Serialize)]
 pub struct ExtAccountInfo {
@@ -78,3 +105,55 @@ pub struct RecoveredAccount {
     /// matches the current chain this would be true, otherwise false.
     pub is_valid_for_current_chain: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn assert_round_trip(s: &str) -> EIP1186ProofResponse {
+        let proof: EIP1186ProofResponse = serde_json::from_str(s).unwrap();
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&proof).unwrap(), expected);
+        proof
+    }
+
+    #[test]
+    fn proof_response_serde() {
+        let proof = assert_round_trip(
+            r#"{"accountProof":["0xf90211a0c1b4a7c4d7c3e3d2d5d8a8b2f4a2d6c0c8e2b1f2a3d4c5b6a7988776655443322a0e2c1b0a99887766554433221100ffeeddccbbaa99887766554433221100ff80","0xf8718080a0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f8080808080808080808080808080"],"address":"0x7f0d15c7faae65896648c8273b6d7e43f58fa842","balance":"0xde0b6b3a7640000","codeHash":"0x1f3f0a5e5d9fa8c2d2e02f1e6b7ae5a4d9c4b6c3b2a1f0e9d8c7b6a594837261","nonce":"0x1","storageHash":"0x8d2fa5d9ab2a3c3e8b15c4e4b3a2f1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a594","storageProof":[{"key":"0x0000000000000000000000000000000000000000000000000000000000000000","proof":["0xf8518080a0c1b4a7c4d7c3e3d2d5d8a8b2f4a2d6c0c8e2b1f2a3d4c5b6a7988776655443322808080808080808080808080808080800"],"value":"0x2a"},{"key":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","proof":["0xe2a0390decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e56301"],"value":"0x1"}]}"#,
+        );
+        assert_eq!(proof.storage_proof.len(), 2);
+        assert_eq!(proof.storage_proof[0].key, H256::zero());
+        assert_eq!(proof.storage_proof[0].value, U256::from(42));
+    }
+
+    #[test]
+    fn proof_response_missing_account_serde() {
+        let proof = assert_round_trip(
+            r#"{"accountProof":[],"address":"0x0000000000000000000000000000000000000001","balance":"0x0","codeHash":"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470","nonce":"0x0","storageHash":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","storageProof":[{"key":"0x0000000000000000000000000000000000000000000000000000000000000001","proof":[],"value":"0x0"}]}"#,
+        );
+        assert_eq!(proof.balance, U256::zero());
+        assert!(proof.account_proof.is_empty());
+        assert!(proof.storage_proof[0].proof.is_empty());
+    }
+
+    #[test]
+    fn account_serde() {
+        let s = r#"{"balance":"0x8ac7230489e80000","codeHash":"0x4d3f6adf9d3e59a96e76b3b08dc1aa8fd8c7e25b9bd4c2a8f8d2cb0e4a3c90f5","nonce":"0x2a","storageRoot":"0x8d2fa5d9ab2a3c3e8b15c4e4b3a2f1d0c9b8a7f6e5d4c3b2a1f0e9d8c7b6a594"}"#;
+        let account: Account = serde_json::from_str(s).unwrap();
+        assert_eq!(account.nonce, U64::from(42));
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&account).unwrap(), expected);
+    }
+
+    #[test]
+    fn missing_account_serde() {
+        let s = r#"{"balance":"0x0","codeHash":"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470","nonce":"0x0","storageRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"}"#;
+        let account: Account = serde_json::from_str(s).unwrap();
+        assert_eq!(account.balance, U256::zero());
+        assert_eq!(account.nonce, U64::zero());
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&account).unwrap(), expected);
+    }
+}
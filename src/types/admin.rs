@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Admin types.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Ports the node listens on.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ports {
+    /// UDP discovery port
+    pub discovery: u16,
+    /// TCP listener port
+    pub listener: u16,
+}
+
+/// Information about the node (used by `admin_nodeInfo`).
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfo {
+    /// Node id
+    pub id: String,
+    /// Client name
+    pub name: String,
+    /// Enode URL
+    pub enode: String,
+    /// Ethereum node record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enr: Option<String>,
+    /// IP address
+    pub ip: String,
+    /// Ports
+    pub ports: Ports,
+    /// Listening address
+    pub listen_addr: String,
+    /// Protocol specific information, by protocol name
+    pub protocols: BTreeMap<String, Value>,
+}
+
+/// Network information about a peer.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerNetworkInfo {
+    /// Local endpoint of the connection
+    pub local_address: String,
+    /// Remote endpoint of the connection
+    pub remote_address: String,
+    /// Whether the connection was initiated by the peer
+    pub inbound: bool,
+    /// Whether the peer is trusted
+    pub trusted: bool,
+    /// Whether the peer is a static peer
+    #[serde(rename = "static")]
+    pub static_node: bool,
+}
+
+/// Information about a connected peer (used by `admin_peers`).
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeerInfo {
+    /// Enode URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enode: Option<String>,
+    /// Ethereum node record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enr: Option<String>,
+    /// Node id
+    pub id: String,
+    /// Client name
+    pub name: String,
+    /// Advertised capabilities
+    pub caps: Vec<String>,
+    /// Network information
+    pub network: PeerNetworkInfo,
+    /// Protocol specific information, by protocol name
+    pub protocols: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+
+    #[test]
+    fn node_info_serde() {
+        // As returned by geth, except for `difficulty`, a bare number too
+        // large for `serde_json::Value` to keep exactly, given in hex.
+        let s = r#"{"id":"44826a5d6a55f88a18298bca4773fca5749cdc3a5c9f308aa7d810e9b31123f3","name":"Geth/v1.13.14-stable-2bd6bd01/linux-amd64/go1.21.7","enode":"enode://4aeb4ab6c14b23e2c4cfdce879c04b0748a20d8e9b59e25ded2a08143e265c6c25936e74cbc8e641e3312ca288673d91f2f93f8e277de3cfa444ecdaaf982052@157.90.35.166:30303","enr":"enr:-Jy4QLz3RJNIm7KSDjuSjzNzgXv1l1jOsbOUNFBVM8n0oLR_o6yz2TbUj-u6AwsMmWpdc9L0Y2CX3mkiiB61fCMuDoaGAY7MWm6Eg2V0aMfGhPCv0OOAgmlkgnY0gmlwhMCoAQqJc2VjcDI1NmsxoQNK60q2wUsj4sTP3Oh5wEsHSKINjptZ4l3tKggUPiZcbIRzbmFwwIN0Y3CCdl-DdWRwgnZf","ip":"157.90.35.166","ports":{"discovery":30303,"listener":30303},"listenAddr":"[::]:30303","protocols":{"eth":{"network":1,"difficulty":"0xc70d815d562d3cfa955","genesis":"0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3","config":{"chainId":1},"head":"0x2ae7bdc5c4b10fe53e3d5d0b4c2b125a2cc0e3f6ad72aba7bc38a9c84f9ac6fd"},"snap":{}}}"#;
+        let info: NodeInfo = serde_json::from_str(s).unwrap();
+        assert_eq!(info.ports.discovery, 30303);
+        assert_eq!(info.protocols["eth"]["network"], 1);
+        assert_eq!(
+            serde_json::from_value::<U256>(info.protocols["eth"]["difficulty"].clone()).unwrap(),
+            U256::from_dec_str("58750003716598352816469").unwrap()
+        );
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&info).unwrap(), expected);
+    }
+
+    #[test]
+    fn peer_info_serde() {
+        // As returned by geth.
+        let s = r#"[{"enode":"enode://2bc7b9a57c7e815a8e07e3f7e8d8cf0a5670a6f627b9ad7531c56b2b11c2783d9f1f0f4e8b38e9fe7a66487fb1e3ab4bd5c6d7486f600c58f6ff3e32fb5d6a4b@3.209.45.79:30303","id":"c78d6a8bbd84104d78e268d2bbeb6b260dab8eaf6c2e0e1a8d1ad4ed6d358d2a","name":"Geth/v1.13.5-stable-916d6a44/linux-amd64/go1.21.4","caps":["eth/68","snap/1"],"network":{"localAddress":"10.0.0.2:46314","remoteAddress":"3.209.45.79:30303","inbound":false,"trusted":false,"static":true},"protocols":{"eth":{"version":68},"snap":{"version":1}}}]"#;
+        let peers: Vec<PeerInfo> = serde_json::from_str(s).unwrap();
+        assert!(peers[0].network.static_node);
+        assert!(!peers[0].network.inbound);
+        assert_eq!(peers[0].enr, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&peers).unwrap(), expected);
+    }
+}
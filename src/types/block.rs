@@ -20,9 +20,10 @@ use std::collections::BTreeMap;
 use std::ops::Deref;
 
 use crate::types::{Bytes, Transaction};
-use ethereum_types::{Bloom as H2048, H160, H256, U256};
+use ethereum_types::{Bloom as H2048, H160, H256, H64, U256};
+use serde::de::DeserializeOwned;
 use serde::ser::Error;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Block Transactions
 #[derive(Debug)]
@@ -90,10 +91,13 @@ pub struct Block {
     pub transactions: BlockTransactions,
     /// Size in bytes
     pub size: Option<U256>,
+    /// Base fee per gas (EIP-1559), `None` before London
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
 }
 
 /// Block header representation.
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Header {
     /// Hash of the block
@@ -103,7 +107,8 @@ pub struct Header {
     /// Hash of the uncles
     #[serde(rename = "sha3Uncles")]
     pub uncles_hash: H256,
-    /// Authors address
+    /// Authors address, zero when absent as in geth headers
+    #[serde(default)]
     pub author: H160,
     /// Alias of `author`
     pub miner: H160,
@@ -127,10 +132,20 @@ pub struct Header {
     pub timestamp: U256,
     /// Difficulty
     pub difficulty: U256,
-    /// Seal fields
+    /// Mix hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mix_hash: Option<H256>,
+    /// Nonce
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<H64>,
+    /// Seal fields, empty when absent as in geth headers
+    #[serde(default)]
     pub seal_fields: Vec<Bytes>,
     /// Size in bytes
     pub size: Option<U256>,
+    /// Base fee per gas (EIP-1559)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
 }
 
 /// Block representation with additional info.
@@ -177,3 +192,260 @@ impl<T: Serialize> Serialize for Rich<T> {
         }
     }
 }
+
+impl<'a, T: DeserializeOwned> Deserialize<'a> for Rich<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        use serde::de::Error;
+        use serde_json::{from_value, Value};
+
+        // Engine-specific fields can't be told apart from the standard ones,
+        // so everything goes to `inner`.
+        let value: Value = Deserialize::deserialize(deserializer)?;
+        from_value(value)
+            .map(|inner| Rich {
+                inner,
+                extra_info: BTreeMap::new(),
+            })
+            .map_err(|e| D::Error::custom(format!("Invalid rich value: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn block(base_fee_per_gas: Option<U256>) -> Block {
+        Block {
+            hash: Some(
+                "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b"
+                    .parse()
+                    .unwrap(),
+            ),
+            parent_hash: "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add"
+                .parse()
+                .unwrap(),
+            uncles_hash: "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                .parse()
+                .unwrap(),
+            author: H160::repeat_byte(0x95),
+            miner: H160::repeat_byte(0x95),
+            state_root: H256::repeat_byte(0x01),
+            transactions_root: H256::repeat_byte(0x02),
+            receipts_root: H256::repeat_byte(0x03),
+            number: Some(U256::from(19_531_251)),
+            gas_used: U256::from(21_000),
+            gas_limit: U256::from(30_000_000),
+            extra_data: Bytes::default(),
+            logs_bloom: Some(H2048::zero()),
+            timestamp: U256::from(1_722_000_000),
+            difficulty: U256::zero(),
+            total_difficulty: U256::zero(),
+            seal_fields: vec![],
+            uncles: vec![],
+            transactions: BlockTransactions::Hashes(vec![H256::repeat_byte(0x04)]),
+            size: Some(U256::from(0x2a1)),
+            base_fee_per_gas,
+        }
+    }
+
+    // Fields of `Block` that geth doesn't return.
+    const FRONTIER_FIELDS: [&str; 2] = ["author", "sealFields"];
+    // Fields of a geth block that `Block` doesn't carry.
+    const GETH_FIELDS: [&str; 2] = ["mixHash", "nonce"];
+
+    // Compares the whole output of `block` with the one of geth, except for
+    // `FRONTIER_FIELDS` and `GETH_FIELDS`.
+    fn assert_geth_output(block: &Block, geth: &str) {
+        let mut value = serde_json::to_value(block).unwrap();
+        let mut geth: Value = serde_json::from_str(geth).unwrap();
+        for field in FRONTIER_FIELDS {
+            value.as_object_mut().unwrap().remove(field);
+        }
+        for field in GETH_FIELDS {
+            geth.as_object_mut().unwrap().remove(field);
+        }
+        assert_eq!(value, geth);
+    }
+
+    #[test]
+    fn post_london_block_geth_output() {
+        // The fields of `block` in the format of geth, not a captured block.
+        assert_geth_output(
+            &block(Some(U256::from(12_000_000_000u64))),
+            r#"{
+                "baseFeePerGas": "0x2cb417800",
+                "difficulty": "0x0",
+                "extraData": "0x",
+                "gasLimit": "0x1c9c380",
+                "gasUsed": "0x5208",
+                "hash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+                "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "miner": "0x9595959595959595959595959595959595959595",
+                "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "nonce": "0x0000000000000000",
+                "number": "0x12a05f3",
+                "parentHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+                "receiptsRoot": "0x0303030303030303030303030303030303030303030303030303030303030303",
+                "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+                "size": "0x2a1",
+                "stateRoot": "0x0101010101010101010101010101010101010101010101010101010101010101",
+                "timestamp": "0x66a3a280",
+                "totalDifficulty": "0x0",
+                "transactions": [
+                    "0x0404040404040404040404040404040404040404040404040404040404040404"
+                ],
+                "transactionsRoot": "0x0202020202020202020202020202020202020202020202020202020202020202",
+                "uncles": []
+            }"#,
+        );
+    }
+
+    #[test]
+    fn pre_london_block_geth_output() {
+        // The mainnet genesis block as returned by geth's
+        // `eth_getBlockByNumber("0x0", false)`, without `baseFeePerGas`.
+        let empty_root: H256 = "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+            .parse()
+            .unwrap();
+        let genesis = Block {
+            hash: Some(
+                "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                    .parse()
+                    .unwrap(),
+            ),
+            parent_hash: H256::zero(),
+            uncles_hash: "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                .parse()
+                .unwrap(),
+            author: H160::zero(),
+            miner: H160::zero(),
+            state_root: "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+                .parse()
+                .unwrap(),
+            transactions_root: empty_root,
+            receipts_root: empty_root,
+            number: Some(U256::zero()),
+            gas_used: U256::zero(),
+            gas_limit: U256::from(5000),
+            extra_data: Bytes::new(vec![
+                0x11, 0xbb, 0xe8, 0xdb, 0x4e, 0x34, 0x7b, 0x4e, 0x8c, 0x93, 0x7c, 0x1c, 0x83, 0x70,
+                0xe4, 0xb5, 0xed, 0x33, 0xad, 0xb3, 0xdb, 0x69, 0xcb, 0xdb, 0x7a, 0x38, 0xe1, 0xe5,
+                0x0b, 0x1b, 0x82, 0xfa,
+            ]),
+            logs_bloom: Some(H2048::zero()),
+            timestamp: U256::zero(),
+            difficulty: U256::from(0x400000000u64),
+            total_difficulty: U256::from(0x400000000u64),
+            seal_fields: vec![],
+            uncles: vec![],
+            transactions: BlockTransactions::Hashes(vec![]),
+            size: Some(U256::from(540)),
+            base_fee_per_gas: None,
+        };
+        assert_geth_output(
+            &genesis,
+            r#"{
+                "difficulty": "0x400000000",
+                "extraData": "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+                "gasLimit": "0x1388",
+                "gasUsed": "0x0",
+                "hash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                "miner": "0x0000000000000000000000000000000000000000",
+                "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "nonce": "0x0000000000000042",
+                "number": "0x0",
+                "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+                "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+                "size": "0x21c",
+                "stateRoot": "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544",
+                "timestamp": "0x0",
+                "totalDifficulty": "0x400000000",
+                "transactions": [],
+                "transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+                "uncles": []
+            }"#,
+        );
+    }
+
+    #[test]
+    fn header_base_fee_serde() {
+        let header = Header {
+            hash: None,
+            parent_hash: H256::zero(),
+            uncles_hash: H256::zero(),
+            author: H160::zero(),
+            miner: H160::zero(),
+            state_root: H256::zero(),
+            transactions_root: H256::zero(),
+            receipts_root: H256::zero(),
+            number: None,
+            gas_used: U256::zero(),
+            gas_limit: U256::zero(),
+            extra_data: Bytes::default(),
+            logs_bloom: H2048::zero(),
+            timestamp: U256::zero(),
+            difficulty: U256::zero(),
+            mix_hash: None,
+            nonce: None,
+            seal_fields: vec![],
+            size: None,
+            base_fee_per_gas: None,
+        };
+        let value = serde_json::to_value(&header).unwrap();
+        assert!(value.get("baseFeePerGas").is_none());
+
+        let header = Header {
+            base_fee_per_gas: Some(U256::from(7)),
+            ..header
+        };
+        let value = serde_json::to_value(&header).unwrap();
+        assert_eq!(value["baseFeePerGas"], "0x7");
+        assert_eq!(serde_json::from_value::<Header>(value).unwrap(), header);
+    }
+
+    #[test]
+    fn geth_header_deserialize() {
+        // The mainnet genesis header as returned by geth's
+        // `eth_getHeaderByNumber("0x0")`, without `author` and `sealFields`.
+        let header: Header = serde_json::from_str(r#"{
+            "difficulty": "0x400000000",
+            "extraData": "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+            "gasLimit": "0x1388",
+            "gasUsed": "0x0",
+            "hash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "miner": "0x0000000000000000000000000000000000000000",
+            "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "nonce": "0x0000000000000042",
+            "number": "0x0",
+            "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+            "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+            "stateRoot": "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544",
+            "timestamp": "0x0",
+            "transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+        }"#)
+        .unwrap();
+        assert_eq!(
+            header.hash,
+            Some(
+                "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(header.number, Some(U256::zero()));
+        assert_eq!(header.difficulty, U256::from(0x400000000u64));
+        assert_eq!(header.nonce, Some(H64::from_low_u64_be(0x42)));
+        assert_eq!(header.author, H160::zero());
+        assert!(header.seal_fields.is_empty());
+        assert_eq!(header.size, None);
+        assert_eq!(header.base_fee_per_gas, None);
+    }
+}
@@ -22,7 +22,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 
 /// Represents rpc api block number param.
-#[derive(Debug, PartialEq, Clone, Hash, Eq)]
+#[derive(Debug, Default, PartialEq, Clone, Hash, Eq)]
 pub enum BlockNumber {
     /// Hash
     Hash {
@@ -34,6 +34,7 @@ pub enum BlockNumber {
     /// Number
     Num(u64),
     /// Latest block
+    #[default]
     Latest,
     /// Earliest block (genesis)
     Earliest,
@@ -41,12 +42,6 @@ pub enum BlockNumber {
     Pending,
 }
 
-impl Default for BlockNumber {
-    fn default() -> Self {
-        BlockNumber::Latest
-    }
-}
-
 impl<'a> Deserialize<'a> for BlockNumber {
     fn deserialize<D>(deserializer: D) -> Result<BlockNumber, D::Error>
     where
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Bundle simulation types (`eth_callMany`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BlockNumber, BlockOverrides, Bytes, CallRequest};
+
+/// Ordered calls to simulate in the same block.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bundle {
+    /// Calls, executed in order
+    pub transactions: Vec<CallRequest>,
+    /// Overrides of the block context
+    pub block_override: Option<BlockOverrides>,
+}
+
+/// State the bundles are simulated on top of.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateContext {
+    /// Block whose state is used, the latest one if omitted
+    pub block_number: Option<BlockNumber>,
+    /// Amount of transactions of the block executed before the bundles, all
+    /// of them if omitted or negative
+    pub transaction_index: Option<i64>,
+}
+
+/// Result of a simulated call of a bundle.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthCallResponse {
+    /// Output data, if the call succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Bytes>,
+    /// Error, if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_context_deserialize() {
+        let context: StateContext =
+            serde_json::from_str(r#"{"blockNumber":"0x10","transactionIndex":-1}"#).unwrap();
+        assert_eq!(context.block_number, Some(BlockNumber::Num(16)));
+        assert_eq!(context.transaction_index, Some(-1));
+        assert_eq!(
+            serde_json::from_str::<StateContext>("{}").unwrap(),
+            StateContext::default()
+        );
+    }
+
+    #[test]
+    fn eth_call_response_serialize() {
+        let ok = EthCallResponse {
+            value: Some(Bytes::new(vec![0x2a])),
+            error: None,
+        };
+        assert_eq!(serde_json::to_string(&ok).unwrap(), r#"{"value":"0x2a"}"#);
+        let failed = EthCallResponse {
+            value: None,
+            error: Some("execution reverted".into()),
+        };
+        assert_eq!(
+            serde_json::to_string(&failed).unwrap(),
+            r#"{"error":"execution reverted"}"#
+        );
+    }
+}
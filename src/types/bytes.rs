@@ -133,4 +133,24 @@ mod tests {
         assert_eq!(bytes5, Bytes(vec![0x12]));
         assert_eq!(bytes6, Bytes(vec![0x1, 0x23]));
     }
+
+    #[test]
+    fn test_bytes_deserialize_large() {
+        let len = 3 * 1024 * 1024 / 2;
+        let s = format!(r#""0x{}""#, "ab".repeat(len));
+        let bytes: Bytes = serde_json::from_str(&s).unwrap();
+        assert_eq!(bytes.0.len(), len);
+        assert!(bytes.0.iter().all(|b| *b == 0xab));
+    }
+
+    #[test]
+    fn test_bytes_typed_receipts() {
+        // A legacy receipt followed by an EIP-1559 receipt with its type prefix.
+        let s = r#"["0xf901090183a3eb2db9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c0","0x02f901090183a3eb2db9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c0"]"#;
+        let receipts: Vec<Bytes> = serde_json::from_str(s).unwrap();
+        assert_eq!(receipts[0].0[0], 0xf9);
+        assert_eq!(receipts[1].0[0], 0x02);
+        assert_eq!(receipts[1].0[1..], receipts[0].0[..]);
+        assert_eq!(serde_json::to_string(&receipts).unwrap(), s);
+    }
 }
@@ -16,13 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::Bytes;
-use ethereum_types::{H160, U256};
-use serde::Deserialize;
+use super::quantity::{deserialize_optional_u256_flexible, deserialize_optional_u64_quantity};
+use crate::types::{AccessList, Bytes};
+use ethereum_types::{H160, H256, U256, U64};
+use serde::{Deserialize, Serialize};
 
-/// Call request
-#[derive(Debug, Default, PartialEq, Deserialize, Clone)]
-#[serde(deny_unknown_fields)]
+/// Call request. The call data is read from either `data` or `input` and
+/// serialized as `input`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(try_from = "RawCallRequest")]
 #[serde(rename_all = "camelCase")]
 pub struct CallRequest {
     /// From
@@ -31,14 +33,558 @@ pub struct CallRequest {
     pub to: Option<H160>,
     /// Gas Price
     pub gas_price: Option<U256>,
+    /// Max fee per gas
+    pub max_fee_per_gas: Option<U256>,
+    /// Max priority fee per gas
+    pub max_priority_fee_per_gas: Option<U256>,
     /// Gas
     pub gas: Option<U256>,
     /// Value
     pub value: Option<U256>,
     /// Data
+    #[serde(rename = "input")]
     pub data: Option<Bytes>,
     /// Nonce
     pub nonce: Option<U256>,
-    /// type
-    pub r#type: Option<U256>,
+    /// Access list
+    pub access_list: Option<AccessList>,
+    /// Chain id
+    pub chain_id: Option<U64>,
+    /// Transaction type
+    #[serde(rename = "type")]
+    pub transaction_type: Option<U64>,
+    /// Max fee per blob gas
+    pub max_fee_per_blob_gas: Option<U256>,
+    /// Versioned hashes of the blobs
+    pub blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+struct RawCallRequest {
+    from: Option<H160>,
+    to: Option<H160>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    gas_price: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_fee_per_gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_priority_fee_per_gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    value: Option<U256>,
+    data: Option<Bytes>,
+    input: Option<Bytes>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    nonce: Option<U256>,
+    access_list: Option<AccessList>,
+    #[serde(default, deserialize_with = "deserialize_optional_u64_quantity")]
+    chain_id: Option<U64>,
+    #[serde(rename = "type")]
+    transaction_type: Option<U64>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_fee_per_blob_gas: Option<U256>,
+    blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+impl TryFrom<RawCallRequest> for CallRequest {
+    type Error = String;
+
+    fn try_from(raw: RawCallRequest) -> Result<CallRequest, String> {
+        Ok(CallRequest {
+            from: raw.from,
+            to: raw.to,
+            gas_price: raw.gas_price,
+            max_fee_per_gas: raw.max_fee_per_gas,
+            max_priority_fee_per_gas: raw.max_priority_fee_per_gas,
+            gas: raw.gas,
+            value: raw.value,
+            data: merge_input(raw.data, raw.input)?,
+            nonce: raw.nonce,
+            access_list: raw.access_list,
+            chain_id: raw.chain_id,
+            transaction_type: raw.transaction_type,
+            max_fee_per_blob_gas: raw.max_fee_per_blob_gas,
+            blob_versioned_hashes: raw.blob_versioned_hashes,
+        })
+    }
+}
+
+/// Merges the call data given as `data` and as `input`, which must be equal
+/// if both are given.
+pub(crate) fn merge_input(
+    data: Option<Bytes>,
+    input: Option<Bytes>,
+) -> Result<Option<Bytes>, String> {
+    match (data, input) {
+        (Some(data), Some(input)) if data != input => Err(
+            r#"Both "data" and "input" are set and not equal. Please use "input" to pass transaction call data"#
+                .to_string(),
+        ),
+        (data, input) => Ok(input.or(data)),
+    }
+}
+
+impl CallRequest {
+    /// Returns true if both `gasPrice` and an EIP-1559 fee field are given.
+    /// Such requests parse fine, so implementers decide whether to reject
+    /// them.
+    pub fn has_conflicting_fees(&self) -> bool {
+        self.gas_price.is_some()
+            && (self.max_fee_per_gas.is_some() || self.max_priority_fee_per_gas.is_some())
+    }
+
+    /// Returns the gas price the call pays given the block base fee, or
+    /// `None` if no fee field is given. With the EIP-1559 fields that is
+    /// `min(maxFeePerGas, baseFee + maxPriorityFeePerGas)`, a missing
+    /// priority fee counting as zero and a missing max fee as no cap; they
+    /// take precedence over `gasPrice`.
+    pub fn effective_gas_price(&self, base_fee: U256) -> Option<U256> {
+        if self.max_fee_per_gas.is_none() && self.max_priority_fee_per_gas.is_none() {
+            return self.gas_price;
+        }
+        let price = base_fee.saturating_add(self.max_priority_fee_per_gas.unwrap_or_default());
+        Some(match self.max_fee_per_gas {
+            Some(max_fee_per_gas) => price.min(max_fee_per_gas),
+            None => price,
+        })
+    }
+}
+
+impl CallRequest {
+    /// Returns a builder for a request with every field unset.
+    ///
+    /// ```
+    /// use web3_rpc_core::types::{Bytes, CallRequest};
+    /// use ethereum_types::{H160, U256};
+    ///
+    /// let request = CallRequest::builder()
+    ///     .to(H160::repeat_byte(0x11))
+    ///     .gas(U256::from(21000))
+    ///     .data(Bytes::new(vec![0x70, 0xa0, 0x82, 0x31]))
+    ///     .build();
+    /// assert_eq!(request.gas, Some(U256::from(21000)));
+    /// assert_eq!(request.from, None);
+    /// ```
+    pub fn builder() -> CallRequestBuilder {
+        CallRequestBuilder::default()
+    }
+
+    /// Returns a request calling the given address, with every other field
+    /// unset.
+    ///
+    /// ```
+    /// use web3_rpc_core::types::{Bytes, CallRequest};
+    /// use ethereum_types::H160;
+    ///
+    /// let request = CallRequest::to(H160::repeat_byte(0x11)).with_data(Bytes::new(vec![0x01]));
+    /// assert_eq!(request.to, Some(H160::repeat_byte(0x11)));
+    /// assert_eq!(request.data, Some(Bytes::new(vec![0x01])));
+    /// ```
+    pub fn to(address: H160) -> CallRequest {
+        CallRequest {
+            to: Some(address),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the sender.
+    pub fn with_from(mut self, from: H160) -> CallRequest {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the call data.
+    pub fn with_data(mut self, data: Bytes) -> CallRequest {
+        self.data = Some(data);
+        self
+    }
+
+    /// Sets the value.
+    pub fn with_value(mut self, value: U256) -> CallRequest {
+        self.value = Some(value);
+        self
+    }
+}
+
+/// Builder for `CallRequest`, leaving unset fields `None`.
+#[derive(Debug, Default, Clone)]
+pub struct CallRequestBuilder {
+    request: CallRequest,
+}
+
+impl CallRequestBuilder {
+    /// Sets the sender.
+    pub fn from(mut self, from: H160) -> CallRequestBuilder {
+        self.request.from = Some(from);
+        self
+    }
+
+    /// Sets the recipient.
+    pub fn to(mut self, to: H160) -> CallRequestBuilder {
+        self.request.to = Some(to);
+        self
+    }
+
+    /// Sets the gas limit.
+    pub fn gas(mut self, gas: U256) -> CallRequestBuilder {
+        self.request.gas = Some(gas);
+        self
+    }
+
+    /// Sets the gas price.
+    pub fn gas_price(mut self, gas_price: U256) -> CallRequestBuilder {
+        self.request.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Sets the max fee per gas.
+    pub fn max_fee_per_gas(mut self, max_fee_per_gas: U256) -> CallRequestBuilder {
+        self.request.max_fee_per_gas = Some(max_fee_per_gas);
+        self
+    }
+
+    /// Sets the max priority fee per gas.
+    pub fn max_priority_fee_per_gas(
+        mut self,
+        max_priority_fee_per_gas: U256,
+    ) -> CallRequestBuilder {
+        self.request.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Sets the value.
+    pub fn value(mut self, value: U256) -> CallRequestBuilder {
+        self.request.value = Some(value);
+        self
+    }
+
+    /// Sets the call data.
+    pub fn data(mut self, data: Bytes) -> CallRequestBuilder {
+        self.request.data = Some(data);
+        self
+    }
+
+    /// Sets the nonce.
+    pub fn nonce(mut self, nonce: U256) -> CallRequestBuilder {
+        self.request.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the access list.
+    pub fn access_list(mut self, access_list: AccessList) -> CallRequestBuilder {
+        self.request.access_list = Some(access_list);
+        self
+    }
+
+    /// Sets the chain id.
+    pub fn chain_id(mut self, chain_id: U64) -> CallRequestBuilder {
+        self.request.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the transaction type.
+    pub fn transaction_type(mut self, transaction_type: U64) -> CallRequestBuilder {
+        self.request.transaction_type = Some(transaction_type);
+        self
+    }
+
+    /// Sets the max fee per blob gas.
+    pub fn max_fee_per_blob_gas(mut self, max_fee_per_blob_gas: U256) -> CallRequestBuilder {
+        self.request.max_fee_per_blob_gas = Some(max_fee_per_blob_gas);
+        self
+    }
+
+    /// Sets the versioned hashes of the blobs.
+    pub fn blob_versioned_hashes(mut self, blob_versioned_hashes: Vec<H256>) -> CallRequestBuilder {
+        self.request.blob_versioned_hashes = Some(blob_versioned_hashes);
+        self
+    }
+
+    /// Returns the request.
+    pub fn build(self) -> CallRequest {
+        self.request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AccessListItem;
+    use ethereum_types::H256;
+    use std::str::FromStr;
+
+    #[test]
+    fn call_request_ethers_deserialize() {
+        // As sent by ethers v6 to eth_estimateGas.
+        let s = r#"{
+            "type": "0x2",
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "data": "0xa9059cbb"
+        }"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized,
+            CallRequest {
+                from: Some(H160::from_str("9b2055d370f73ec7d8a03e965129118dc8f5bf83").unwrap()),
+                to: Some(H160::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap()),
+                gas_price: None,
+                max_fee_per_gas: Some(U256::from(10_000_000_000u64)),
+                max_priority_fee_per_gas: Some(U256::from(1_000_000_000)),
+                gas: None,
+                value: None,
+                data: Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb])),
+                nonce: None,
+                access_list: None,
+                chain_id: None,
+                transaction_type: Some(U64::from(2)),
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            }
+        );
+        assert!(!deserialized.has_conflicting_fees());
+        assert_eq!(
+            deserialized.effective_gas_price(U256::from(7_000_000_000u64)),
+            Some(U256::from(8_000_000_000u64))
+        );
+        assert_eq!(
+            deserialized.effective_gas_price(U256::from(9_500_000_000u64)),
+            Some(U256::from(10_000_000_000u64))
+        );
+    }
+
+    #[test]
+    fn call_request_web3js_deserialize() {
+        // As sent by web3.js 1.x, with both the legacy and EIP-1559 fees.
+        let s = r#"{
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "gas": "0x76c0",
+            "gasPrice": "0x9184e72a000",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "value": "0x0",
+            "data": "0xa9059cbb"
+        }"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized.gas_price,
+            Some(U256::from(10_000_000_000_000u64))
+        );
+        assert_eq!(
+            deserialized.max_fee_per_gas,
+            Some(U256::from(10_000_000_000u64))
+        );
+        assert!(deserialized.has_conflicting_fees());
+    }
+
+    #[test]
+    fn call_request_effective_gas_price() {
+        let base_fee = U256::from(100);
+        assert_eq!(CallRequest::default().effective_gas_price(base_fee), None);
+        let legacy = CallRequest {
+            gas_price: Some(U256::from(50)),
+            ..Default::default()
+        };
+        assert_eq!(legacy.effective_gas_price(base_fee), Some(U256::from(50)));
+        let tip_only = CallRequest {
+            max_priority_fee_per_gas: Some(U256::from(2)),
+            ..Default::default()
+        };
+        assert_eq!(
+            tip_only.effective_gas_price(base_fee),
+            Some(U256::from(102))
+        );
+        let cap_only = CallRequest {
+            max_fee_per_gas: Some(U256::from(90)),
+            ..Default::default()
+        };
+        assert_eq!(cap_only.effective_gas_price(base_fee), Some(U256::from(90)));
+    }
+
+    #[test]
+    fn call_request_access_list_deserialize() {
+        let s = r#"{"to":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","accessList":[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081"]},{"address":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","storageKeys":[]}]}"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        let access_list = deserialized.access_list.unwrap();
+        assert_eq!(access_list.len(), 2);
+        assert_eq!(
+            access_list[0].storage_keys,
+            vec![H256::from_low_u64_be(0x81)]
+        );
+        assert!(access_list[1].storage_keys.is_empty());
+
+        let empty: CallRequest = serde_json::from_str(r#"{"accessList":[]}"#).unwrap();
+        assert_eq!(empty.access_list, Some(vec![]));
+    }
+
+    #[test]
+    fn call_request_input_deserialize() {
+        let calldata = Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb]));
+        for s in [
+            r#"{"data":"0xa9059cbb"}"#,
+            r#"{"input":"0xa9059cbb"}"#,
+            r#"{"data":"0xa9059cbb","input":"0xa9059cbb"}"#,
+        ] {
+            let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+            assert_eq!(deserialized.data, calldata);
+        }
+
+        let err = serde_json::from_str::<CallRequest>(r#"{"data":"0xa9059cbb","input":"0x"}"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Both "data" and "input" are set and not equal. Please use "input" to pass transaction call data"#
+        );
+        assert!(serde_json::from_str::<CallRequest>(r#"{"foo":"0x"}"#).is_err());
+    }
+
+    #[test]
+    fn call_request_input_serialize() {
+        let request = CallRequest {
+            data: Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb])),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_value(&request).unwrap();
+        assert_eq!(serialized["input"], "0xa9059cbb");
+        assert!(serialized.get("data").is_none());
+        assert_eq!(
+            serde_json::from_value::<CallRequest>(serialized).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn call_request_builder() {
+        assert_eq!(CallRequest::builder().build(), CallRequest::default());
+
+        let from = H160::repeat_byte(0x01);
+        let to = H160::repeat_byte(0x02);
+        let data = Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb]);
+        let access_list = vec![AccessListItem {
+            address: to,
+            storage_keys: vec![H256::from_low_u64_be(1)],
+        }];
+        let request = CallRequest::builder()
+            .from(from)
+            .to(to)
+            .gas(U256::from(1))
+            .gas_price(U256::from(2))
+            .max_fee_per_gas(U256::from(3))
+            .max_priority_fee_per_gas(U256::from(4))
+            .value(U256::from(5))
+            .data(data.clone())
+            .nonce(U256::from(6))
+            .access_list(access_list.clone())
+            .chain_id(U64::from(1))
+            .transaction_type(U64::from(3))
+            .max_fee_per_blob_gas(U256::from(7))
+            .blob_versioned_hashes(vec![H256::from_low_u64_be(8)])
+            .build();
+        assert_eq!(
+            request,
+            CallRequest {
+                from: Some(from),
+                to: Some(to),
+                gas_price: Some(U256::from(2)),
+                max_fee_per_gas: Some(U256::from(3)),
+                max_priority_fee_per_gas: Some(U256::from(4)),
+                gas: Some(U256::from(1)),
+                value: Some(U256::from(5)),
+                data: Some(data),
+                nonce: Some(U256::from(6)),
+                access_list: Some(access_list),
+                chain_id: Some(U64::from(1)),
+                transaction_type: Some(U64::from(3)),
+                max_fee_per_blob_gas: Some(U256::from(7)),
+                blob_versioned_hashes: Some(vec![H256::from_low_u64_be(8)]),
+            }
+        );
+    }
+
+    #[test]
+    fn call_request_to() {
+        let to = H160::repeat_byte(0x02);
+        let data = Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb]);
+        let request = CallRequest::to(to)
+            .with_from(H160::repeat_byte(0x01))
+            .with_data(data.clone())
+            .with_value(U256::one());
+        assert_eq!(
+            request,
+            CallRequest::builder()
+                .from(H160::repeat_byte(0x01))
+                .to(to)
+                .data(data)
+                .value(U256::one())
+                .build()
+        );
+    }
+
+    #[test]
+    fn call_request_type_deserialize() {
+        let s = r#"{"type":"0x1","gasPrice":"0x3b9aca00","accessList":[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":[]}]}"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(1)));
+        assert_eq!(deserialized.access_list.map(|list| list.len()), Some(1));
+
+        let s =
+            r#"{"type":"0x2","maxFeePerGas":"0x2540be400","maxPriorityFeePerGas":"0x3b9aca00"}"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(2)));
+        assert_eq!(
+            deserialized.max_fee_per_gas,
+            Some(U256::from(10_000_000_000u64))
+        );
+
+        // Unknown types are left to the implementer.
+        let deserialized: CallRequest = serde_json::from_str(r#"{"type":"0x7e"}"#).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(0x7e)));
+        assert_eq!(serde_json::to_value(&deserialized).unwrap()["type"], "0x7e");
+    }
+
+    #[test]
+    fn call_request_flexible_quantities() {
+        let fields = [
+            "gas",
+            "gasPrice",
+            "maxFeePerGas",
+            "maxPriorityFeePerGas",
+            "maxFeePerBlobGas",
+            "value",
+            "nonce",
+            "chainId",
+        ];
+        for field in fields {
+            for encoding in [r#""0x1a""#, r#""26""#, "26"] {
+                let s = format!(r#"{{"{}":{}}}"#, field, encoding);
+                let deserialized: CallRequest = serde_json::from_str(&s).unwrap();
+                assert_eq!(
+                    serde_json::to_value(&deserialized).unwrap()[field],
+                    "0x1a",
+                    "{}",
+                    s
+                );
+            }
+            let s = format!(r#"{{"{}":"0x"}}"#, field);
+            assert!(serde_json::from_str::<CallRequest>(&s).is_err(), "{}", s);
+        }
+
+        // A quantity above u64 sent as a decimal string, and 1 ether sent as
+        // a number.
+        let s = r#"{"value":"100000000000000000000000"}"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized.value,
+            Some(U256::from_dec_str("100000000000000000000000").unwrap())
+        );
+        let s = r#"{"value":1000000000000000000}"#;
+        let deserialized: CallRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.value, Some(U256::from(10).pow(U256::from(18))));
+    }
 }
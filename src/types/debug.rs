@@ -0,0 +1,415 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Debug namespace types.
+
+use std::collections::BTreeMap;
+
+use ethereum_types::{H160, H256, U256};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::types::{BlockOverrides, Bytes, CallStateOverride, RichBlock};
+
+/// Tracers built into geth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GethDebugBuiltInTracerType {
+    /// Call tree tracer
+    #[serde(rename = "callTracer")]
+    CallTracer,
+    /// Pre-state tracer
+    #[serde(rename = "prestateTracer")]
+    PreStateTracer,
+    /// Function selector tracer
+    #[serde(rename = "4byteTracer")]
+    FourByteTracer,
+    /// Tracer producing no output
+    #[serde(rename = "noopTracer")]
+    NoopTracer,
+    /// Tracer multiplexing several other tracers
+    #[serde(rename = "muxTracer")]
+    MuxTracer,
+    /// Parity-style flat call tracer
+    #[serde(rename = "flatCallTracer")]
+    FlatCallTracer,
+}
+
+/// Tracer selected by the `tracer` option.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GethDebugTracerType {
+    /// One of the built-in tracers
+    BuiltInTracer(GethDebugBuiltInTracerType),
+    /// Body of a JavaScript tracer
+    JsTracer(String),
+}
+
+/// Tracing options of the debug trace methods.
+///
+/// Every field is optional and defaults to geth's behaviour: the struct
+/// logger with storage and stack enabled, memory and return data disabled.
+/// Unknown fields are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GethDebugTracingOptions {
+    /// Disable storage capture
+    #[serde(default)]
+    pub disable_storage: bool,
+    /// Disable stack capture
+    #[serde(default)]
+    pub disable_stack: bool,
+    /// Enable memory capture
+    #[serde(default)]
+    pub enable_memory: bool,
+    /// Enable return data capture
+    #[serde(default)]
+    pub enable_return_data: bool,
+    /// Tracer name or JavaScript tracer body, the struct logger if `None`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracer: Option<GethDebugTracerType>,
+    /// Tracer specific configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracer_config: Option<Value>,
+    /// Tracing timeout, e.g. `"10s"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+}
+
+/// Options of `debug_traceCall`: the tracing options, plus the state and
+/// block overrides the call is executed with.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GethDebugTracingCallOptions {
+    /// Tracing options
+    #[serde(flatten)]
+    pub tracing_options: GethDebugTracingOptions,
+    /// Overrides of the state the call is executed on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_overrides: Option<CallStateOverride>,
+    /// Overrides of the block context the call is executed in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_overrides: Option<BlockOverrides>,
+}
+
+/// Trace of a single transaction of a traced block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxTraceResult {
+    /// Transaction hash, omitted by older geth versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<H256>,
+    /// Tracer output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Tracing error
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Storage slot returned by `debug_storageRangeAt`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageEntry {
+    /// Slot key, `None` if its preimage is unknown
+    pub key: Option<H256>,
+    /// Slot value
+    pub value: H256,
+}
+
+/// The result of a `debug_storageRangeAt` call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageRangeResult {
+    /// Storage slots keyed by the keccak hash of their key
+    pub storage: BTreeMap<H256, StorageEntry>,
+    /// Hashed key to start the next page from, `None` on the last page
+    pub next_key: Option<H256>,
+}
+
+/// Account returned by `debug_accountRange`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRangeEntry {
+    /// Balance, encoded as a decimal string
+    #[serde(
+        serialize_with = "serialize_dec_u256",
+        deserialize_with = "deserialize_dec_u256"
+    )]
+    pub balance: U256,
+    /// Nonce
+    pub nonce: u64,
+    /// Storage root
+    pub root: H256,
+    /// Code hash
+    pub code_hash: H256,
+    /// Code, omitted when skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// Storage values by slot, omitted when skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<H256, String>>,
+    /// Address, omitted if its preimage is unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<H160>,
+    /// Hashed address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Bytes>,
+}
+
+/// The result of a `debug_accountRange` call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountRangeResult {
+    /// State root of the block
+    pub root: H256,
+    /// Accounts by address
+    pub accounts: BTreeMap<H160, AccountRangeEntry>,
+    /// Opaque (base64) key to start the next page from, `None` on the last
+    /// page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+}
+
+/// Block rejected during import, as returned by `debug_getBadBlocks`.
+#[derive(Debug, Serialize)]
+pub struct BadBlock {
+    /// Block hash
+    pub hash: H256,
+    /// RLP encoded block
+    pub rlp: Bytes,
+    /// Decoded block
+    pub block: RichBlock,
+}
+
+fn serialize_dec_u256<S>(value: &U256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+fn deserialize_dec_u256<'a, D>(deserializer: D) -> Result<U256, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    U256::from_dec_str(&s).map_err(|e| D::Error::custom(format!("Invalid balance: {:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Block, BlockTransactions, Rich};
+    use ethereum_types::Bloom;
+    use serde_json::json;
+    use std::str::FromStr;
+
+    #[test]
+    fn tracing_options_defaults() {
+        let options: GethDebugTracingOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(options, GethDebugTracingOptions::default());
+    }
+
+    #[test]
+    fn tracing_options_call_tracer() {
+        // As sent by Foundry.
+        let options: GethDebugTracingOptions = serde_json::from_str(
+            r#"{"tracer":"callTracer","tracerConfig":{"onlyTopCall":false,"withLog":true}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            options,
+            GethDebugTracingOptions {
+                tracer: Some(GethDebugTracerType::BuiltInTracer(
+                    GethDebugBuiltInTracerType::CallTracer
+                )),
+                tracer_config: Some(json!({"onlyTopCall": false, "withLog": true})),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn tracing_call_options_serde() {
+        let serialized = r#"{"disableStorage":false,"disableStack":false,"enableMemory":false,"enableReturnData":false,"tracer":"callTracer","blockOverrides":{"number":"0x10","time":"0x66a3a280"}}"#;
+        let options: GethDebugTracingCallOptions = serde_json::from_str(
+            r#"{"tracer":"callTracer","blockOverrides":{"number":"0x10","time":1722000000}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            options,
+            GethDebugTracingCallOptions {
+                tracing_options: GethDebugTracingOptions {
+                    tracer: Some(GethDebugTracerType::BuiltInTracer(
+                        GethDebugBuiltInTracerType::CallTracer
+                    )),
+                    ..Default::default()
+                },
+                state_overrides: None,
+                block_overrides: Some(BlockOverrides {
+                    number: Some(U256::from(0x10)),
+                    time: Some(U256::from(1_722_000_000)),
+                    ..Default::default()
+                }),
+            }
+        );
+        assert_eq!(serde_json::to_string(&options).unwrap(), serialized);
+        assert_eq!(
+            serde_json::from_str::<GethDebugTracingCallOptions>(serialized).unwrap(),
+            options
+        );
+    }
+
+    #[test]
+    fn tracing_options_struct_logger() {
+        // As sent by Hardhat, including the legacy `disableMemory` flag.
+        let options: GethDebugTracingOptions = serde_json::from_str(
+            r#"{"disableStorage":true,"disableMemory":false,"disableStack":false,"timeout":"10s"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            options,
+            GethDebugTracingOptions {
+                disable_storage: true,
+                timeout: Some("10s".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn tracing_options_js_tracer() {
+        let options: GethDebugTracingOptions =
+            serde_json::from_str(r#"{"tracer":"{data: [], fault: function(log) {}, step: function(log) {}, result: function() { return this.data; }}"}"#)
+                .unwrap();
+        assert!(matches!(
+            options.tracer,
+            Some(GethDebugTracerType::JsTracer(_))
+        ));
+        let options: GethDebugTracingOptions =
+            serde_json::from_str(r#"{"tracer":"4byteTracer"}"#).unwrap();
+        assert_eq!(
+            options.tracer,
+            Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::FourByteTracer
+            ))
+        );
+    }
+
+    #[test]
+    fn tx_trace_result_serde() {
+        let s = r#"[{"txHash":"0xd6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa","result":{"gas":"0x5208","type":"CALL"}}]"#;
+        let results: Vec<TxTraceResult> = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            results,
+            vec![TxTraceResult {
+                tx_hash: Some(
+                    H256::from_str(
+                        "d6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa"
+                    )
+                    .unwrap()
+                ),
+                result: Some(json!({"type": "CALL", "gas": "0x5208"})),
+                error: None,
+            }]
+        );
+        assert_eq!(serde_json::to_string(&results).unwrap(), s);
+    }
+
+    #[test]
+    fn tx_trace_result_without_hash() {
+        // Shape returned by older geth versions.
+        let s = r#"[{"result":{"failed":false,"gas":21000,"returnValue":"","structLogs":[]}}]"#;
+        let results: Vec<TxTraceResult> = serde_json::from_str(s).unwrap();
+        assert_eq!(results[0].tx_hash, None);
+        assert_eq!(serde_json::to_string(&results).unwrap(), s);
+    }
+
+    #[test]
+    fn storage_range_result_serde() {
+        let s = r#"{"storage":{"0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563":{"key":"0x0000000000000000000000000000000000000000000000000000000000000000","value":"0x000000000000000000000000000000000000000000000000000000000000002a"},"0xb10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0cf6":{"key":null,"value":"0x0000000000000000000000000000000000000000000000000000000000000001"}},"nextKey":null}"#;
+        let result: StorageRangeResult = serde_json::from_str(s).unwrap();
+        assert_eq!(result.storage.len(), 2);
+        assert_eq!(result.next_key, None);
+        let hashed_key =
+            H256::from_str("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563")
+                .unwrap();
+        assert_eq!(result.storage[&hashed_key].key, Some(H256::zero()));
+        assert_eq!(result.storage[&hashed_key].value, H256::from_low_u64_be(42));
+        assert_eq!(serde_json::to_string(&result).unwrap(), s);
+    }
+
+    #[test]
+    fn account_range_result_serde() {
+        let s = r#"{"root":"0xd5855eb08b3387c0af375e9cdb6acfc05eb8f519e419b874b6ff2ffda7ed1dff","accounts":{"0x0000000000000000000000000000000000000000":{"balance":"0","nonce":0,"root":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","codeHash":"0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470","key":"0x5380c7b7ae81a58eb98d9c78de4a1fd7fd9535fc953ed2be602daaa41767312a"},"0x5fbdb2315678afecb367f032d93f642f64180aa3":{"balance":"1000000000000000000000","nonce":1,"root":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","codeHash":"0x0b6f8f8b5f1ad3492cdb0f41fb3a1d8f2b6e0d0ad1c8c5f7f9a0f0e41b2d1c9a","code":"0x6080604052","address":"0x5fbdb2315678afecb367f032d93f642f64180aa3"}},"next":"AAAAAAAAAAAAAAAAAAAAAAAAAAA="}"#;
+        let result: AccountRangeResult = serde_json::from_str(s).unwrap();
+        let address = H160::from_str("5fbdb2315678afecb367f032d93f642f64180aa3").unwrap();
+        assert_eq!(result.accounts[&address].balance, U256::exp10(21));
+        assert_eq!(result.accounts[&address].nonce, 1);
+        assert_eq!(
+            result.accounts[&address].code,
+            Some(Bytes::new(vec![0x60, 0x80, 0x60, 0x40, 0x52]))
+        );
+        assert_eq!(result.accounts[&H160::zero()].code, None);
+        assert_eq!(serde_json::to_string(&result).unwrap(), s);
+    }
+
+    #[test]
+    fn bad_block_serialize() {
+        let hash =
+            H256::from_str("7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70")
+                .unwrap();
+        let bad_block = BadBlock {
+            hash,
+            rlp: Bytes::new(vec![0xf9, 0x02]),
+            block: Rich {
+                inner: Block {
+                    hash: Some(hash),
+                    parent_hash: H256::zero(),
+                    uncles_hash: H256::zero(),
+                    author: H160::zero(),
+                    miner: H160::zero(),
+                    state_root: H256::zero(),
+                    transactions_root: H256::zero(),
+                    receipts_root: H256::zero(),
+                    number: Some(U256::from(100)),
+                    gas_used: U256::zero(),
+                    gas_limit: U256::zero(),
+                    extra_data: Bytes::default(),
+                    logs_bloom: Some(Bloom::zero()),
+                    timestamp: U256::zero(),
+                    difficulty: U256::zero(),
+                    total_difficulty: U256::zero(),
+                    seal_fields: vec![],
+                    uncles: vec![],
+                    transactions: BlockTransactions::Hashes(vec![]),
+                    size: None,
+                    base_fee_per_gas: None,
+                },
+                extra_info: BTreeMap::new(),
+            },
+        };
+        let value = serde_json::to_value(&bad_block).unwrap();
+        assert_eq!(value["hash"], json!(hash));
+        assert_eq!(value["rlp"], "0xf902");
+        assert_eq!(value["block"]["hash"], json!(hash));
+        assert_eq!(value["block"]["number"], "0x64");
+
+        let bad_blocks: Vec<BadBlock> = vec![];
+        assert_eq!(serde_json::to_string(&bad_blocks).unwrap(), "[]");
+    }
+}
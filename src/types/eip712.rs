@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! EIP-712 typed structured data.
+
+use std::collections::BTreeMap;
+
+use ethereum_types::{H160, H256, U256};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{from_str, from_value, Value};
+
+use crate::types::quantity::deserialize_optional_u256_flexible;
+
+/// Member of a struct type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldType {
+    /// Member name
+    pub name: String,
+    /// Member type
+    pub r#type: String,
+}
+
+/// Signing domain.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EIP712Domain {
+    /// Name of the signing domain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Version of the signing domain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Chain id
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub chain_id: Option<U256>,
+    /// Address of the contract verifying the signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifying_contract: Option<H160>,
+    /// Disambiguating salt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<H256>,
+}
+
+/// Typed structured data to sign.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    /// Struct types, by name
+    pub types: BTreeMap<String, Vec<FieldType>>,
+    /// Type of the message
+    pub primary_type: String,
+    /// Signing domain
+    pub domain: EIP712Domain,
+    /// Message
+    pub message: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTypedData {
+    types: BTreeMap<String, Vec<FieldType>>,
+    primary_type: String,
+    domain: EIP712Domain,
+    message: Value,
+}
+
+impl<'a> Deserialize<'a> for TypedData {
+    fn deserialize<D>(deserializer: D) -> Result<TypedData, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        // Some dapps send the typed data as a JSON-encoded string.
+        let raw: RawTypedData = match Value::deserialize(deserializer)? {
+            Value::String(s) => from_str(&s),
+            v => from_value(v),
+        }
+        .map_err(|err| D::Error::custom(format!("Invalid typed data: {}", err)))?;
+
+        Ok(TypedData {
+            types: raw.types,
+            primary_type: raw.primary_type,
+            domain: raw.domain,
+            message: raw.message,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // As sent by MetaMask.
+    const MAIL: &str = r#"{"types":{"EIP712Domain":[{"name":"name","type":"string"},{"name":"version","type":"string"},{"name":"chainId","type":"uint256"},{"name":"verifyingContract","type":"address"}],"Person":[{"name":"name","type":"string"},{"name":"wallet","type":"address"}],"Mail":[{"name":"from","type":"Person"},{"name":"to","type":"Person"},{"name":"contents","type":"string"}]},"primaryType":"Mail","domain":{"name":"Ether Mail","version":"1","chainId":1,"verifyingContract":"0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"},"message":{"from":{"name":"Cow","wallet":"0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},"to":{"name":"Bob","wallet":"0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},"contents":"Hello, Bob!"}}"#;
+
+    #[test]
+    fn typed_data_deserialize() {
+        let data: TypedData = serde_json::from_str(MAIL).unwrap();
+        assert_eq!(data.primary_type, "Mail");
+        assert_eq!(data.types.len(), 3);
+        assert_eq!(
+            data.types["Person"],
+            vec![
+                FieldType {
+                    name: "name".into(),
+                    r#type: "string".into(),
+                },
+                FieldType {
+                    name: "wallet".into(),
+                    r#type: "address".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            data.domain,
+            EIP712Domain {
+                name: Some("Ether Mail".into()),
+                version: Some("1".into()),
+                chain_id: Some(U256::one()),
+                verifying_contract: Some(H160::repeat_byte(0xcc)),
+                salt: None,
+            }
+        );
+        assert_eq!(data.message["contents"], "Hello, Bob!");
+    }
+
+    #[test]
+    fn typed_data_deserialize_string() {
+        let encoded = serde_json::to_string(MAIL).unwrap();
+        let data: TypedData = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(data, serde_json::from_str(MAIL).unwrap());
+        assert!(serde_json::from_str::<TypedData>(r#""{}""#).is_err());
+    }
+
+    #[test]
+    fn domain_deserialize_empty() {
+        let domain: EIP712Domain = serde_json::from_str("{}").unwrap();
+        assert_eq!(domain, EIP712Domain::default());
+        let domain: EIP712Domain = serde_json::from_str(r#"{"chainId":"0x4"}"#).unwrap();
+        assert_eq!(domain.chain_id, Some(U256::from(4)));
+        assert_eq!(
+            serde_json::to_string(&domain).unwrap(),
+            r#"{"chainId":"0x4"}"#
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fee history types.
+
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+/// Response of `eth_feeHistory`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeHistory {
+    /// Lowest number block of the returned range.
+    pub oldest_block: U256,
+    /// Base fee per gas of each block of the range, followed by the base fee
+    /// of the block after the newest one.
+    pub base_fee_per_gas: Vec<U256>,
+    /// Ratio of gas used to gas limit of each block of the range.
+    pub gas_used_ratio: Vec<f64>,
+    /// Effective priority fees per gas at the requested percentiles of each
+    /// block of the range, if percentiles were requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward: Option<Vec<Vec<U256>>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BlockNumber, Quantity};
+
+    #[test]
+    fn fee_history_params_deserialize() {
+        // As sent by MetaMask.
+        let (count, newest, percentiles): (Quantity, BlockNumber, Option<Vec<f64>>) =
+            serde_json::from_str(r#"["0x5","latest",[10,20,30]]"#).unwrap();
+        assert_eq!(count.value(), U256::from(5));
+        assert_eq!(newest, BlockNumber::Latest);
+        assert_eq!(percentiles, Some(vec![10.0, 20.0, 30.0]));
+
+        let (count, newest, percentiles): (Quantity, BlockNumber, Option<Vec<f64>>) =
+            serde_json::from_str(r#"[4,"0x10c5e6d",null]"#).unwrap();
+        assert_eq!(count.value(), U256::from(4));
+        assert_eq!(newest, BlockNumber::Num(0x10c5e6d));
+        assert_eq!(percentiles, None);
+    }
+
+    #[test]
+    fn fee_history_serde() {
+        // As returned by geth.
+        let s = r#"{"oldestBlock":"0x10c5e69","reward":[["0x5f5e100","0x77359400","0xb2d05e00"],["0x3b9aca00","0x3b9aca00","0x77359400"],["0x5f5e100","0x3b9aca00","0x59682f00"],["0x2faf080","0x5f5e100","0x3b9aca00"],["0x5f5e100","0x5f5e100","0x77359400"]],"baseFeePerGas":["0x74d2cbc6e","0x7565f8b76","0x6e7e6c31b","0x705a9a4c4","0x6ef1bc4d1","0x6e0a60f23"],"gasUsedRatio":[0.5314138666666667,0.11054526666666667,0.5659432666666667,0.46799943333333334,0.4475421]}"#;
+        let history: FeeHistory = serde_json::from_str(s).unwrap();
+        assert_eq!(history.oldest_block, U256::from(0x10c5e69));
+        assert_eq!(
+            history.base_fee_per_gas.len(),
+            history.gas_used_ratio.len() + 1
+        );
+        assert_eq!(history.reward.as_ref().unwrap().len(), 5);
+        let expected: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&history).unwrap(), expected);
+
+        let history = FeeHistory {
+            reward: None,
+            ..history
+        };
+        assert!(!serde_json::to_string(&history).unwrap().contains("reward"));
+    }
+}
@@ -142,6 +142,8 @@ pub struct Filter {
     pub address: Option<FilterAddress>,
     /// Topics
     pub topics: Option<Topic>,
+    /// Maximum number of logs to return
+    pub limit: Option<usize>,
 }
 
 /// Helper for Filter matching.
@@ -333,10 +335,8 @@ impl FilteredParams {
         }
         if let Some(to) = filter.to_block {
             match to {
-                BlockNumber::Num(_) => {
-                    if to.to_min_block_num().unwrap_or(0_u64) < block_number {
-                        out = false;
-                    }
+                BlockNumber::Num(_) if to.to_min_block_num().unwrap_or(0_u64) < block_number => {
+                    out = false;
                 }
                 BlockNumber::Earliest => {
                     out = false;
@@ -447,11 +447,30 @@ impl Serialize for FilterChanges {
     }
 }
 
+impl<'a> Deserialize<'a> for FilterChanges {
+    fn deserialize<D>(deserializer: D) -> Result<FilterChanges, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let v: Vec<Value> = Deserialize::deserialize(deserializer)?;
+
+        if v.is_empty() {
+            return Ok(FilterChanges::Empty);
+        }
+
+        let v = Value::Array(v);
+        from_value(v.clone())
+            .map(FilterChanges::Hashes)
+            .or_else(|_| from_value(v).map(FilterChanges::Logs))
+            .map_err(|err| D::Error::custom(format!("Invalid filter changes: {}", err)))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FilterType {
     Block,
     PendingTransaction,
-    Log(Filter),
+    Log(Box<Filter>),
 }
 
 #[derive(Debug, Clone)]
@@ -494,6 +513,7 @@ mod tests {
             block_hash: None,
             address: Some(VariadicValue::Single(test_address)),
             topics: None,
+            limit: None,
         };
         let address_bloom = FilteredParams::addresses_bloom_filter(&filter.address);
         assert!(FilteredParams::address_in_bloom(
@@ -511,6 +531,7 @@ mod tests {
             block_hash: None,
             address: Some(VariadicValue::Single(test_address)),
             topics: None,
+            limit: None,
         };
         let address_bloom = FilteredParams::addresses_bloom_filter(&filter.address);
         assert!(!FilteredParams::address_in_bloom(
@@ -538,6 +559,7 @@ mod tests {
                 Some(VariadicValue::Single(Some(topic1))),
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter));
@@ -571,6 +593,7 @@ mod tests {
                 Some(VariadicValue::Single(Some(topic1))),
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter));
@@ -592,6 +615,7 @@ mod tests {
             block_hash: None,
             address: None,
             topics: Some(VariadicValue::Multiple(vec![])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter));
@@ -626,6 +650,7 @@ mod tests {
                 Some(VariadicValue::Single(Some(topic1))),
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter.clone()));
@@ -660,6 +685,7 @@ mod tests {
                 Some(VariadicValue::Single(Some(topic1))),
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter.clone()));
@@ -690,6 +716,7 @@ mod tests {
                 None,
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter));
@@ -720,6 +747,7 @@ mod tests {
                 None,
                 Some(VariadicValue::Multiple(vec![Some(topic2), Some(topic3)])),
             ])),
+            limit: None,
         };
         let topics_input = if filter.topics.is_some() {
             let filtered_params = FilteredParams::new(Some(filter));
@@ -733,4 +761,51 @@ mod tests {
             &topics_bloom
         ));
     }
+
+    #[test]
+    fn filter_changes_logs_serde() {
+        let s = r#"[{"address":"0x1a94fce7ef36bc90959e206ba569a12afbc91ca1","topics":["0x241ea03ca20251805084d27d4440371c34a0b85ff108f6bb5611248f73818b80"],"data":"0x0000000000000000000000000000000000000000000000000000000000000001","blockHash":"0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70","blockNumber":"0x5c29fb","transactionHash":"0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50","transactionIndex":"0x1d","logIndex":"0x1d","transactionLogIndex":null,"removed":false}]"#;
+        let changes: FilterChanges = serde_json::from_str(s).unwrap();
+        match changes {
+            FilterChanges::Logs(ref logs) => {
+                assert_eq!(logs.len(), 1);
+                assert_eq!(logs[0].block_number, Some(U256::from(0x5c29fb)));
+            }
+            _ => panic!("expected logs"),
+        }
+        assert_eq!(serde_json::to_string(&changes).unwrap(), s);
+    }
+
+    #[test]
+    fn filter_changes_hashes_serde() {
+        let s = r#"["0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70","0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50"]"#;
+        let changes: FilterChanges = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            changes,
+            FilterChanges::Hashes(vec![
+                H256::from_str("7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70")
+                    .unwrap(),
+                H256::from_str("3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50")
+                    .unwrap(),
+            ])
+        );
+        assert_eq!(serde_json::to_string(&changes).unwrap(), s);
+    }
+
+    #[test]
+    fn filter_changes_empty_serde() {
+        let changes: FilterChanges = serde_json::from_str("[]").unwrap();
+        assert_eq!(changes, FilterChanges::Empty);
+        assert_eq!(serde_json::to_string(&changes).unwrap(), "[]");
+    }
+
+    #[test]
+    fn filter_limit_deserialize() {
+        let filter: Filter = serde_json::from_str(r#"{"fromBlock":"0x1"}"#).unwrap();
+        assert_eq!(filter.limit, None);
+        let filter: Filter = serde_json::from_str(r#"{"fromBlock":"0x1","limit":100}"#).unwrap();
+        assert_eq!(filter.from_block, Some(BlockNumber::Num(1)));
+        assert_eq!(filter.limit, Some(100));
+        assert!(serde_json::from_str::<Filter>(r#"{"limit":-1}"#).is_err());
+    }
 }
@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Output types of the geth built-in tracers.
+
+use std::collections::BTreeMap;
+
+use ethereum_types::{H160, H256, U256, U64};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::types::Bytes;
+
+/// Call frame produced by the `callTracer`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    /// Call type, e.g. `CALL`, `STATICCALL` or `CREATE`
+    pub r#type: String,
+    /// Caller
+    pub from: H160,
+    /// Callee, missing for failed creations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<H160>,
+    /// Transfered value, missing for static and delegate calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// Gas
+    pub gas: U256,
+    /// Gas used
+    pub gas_used: U256,
+    /// Call data
+    pub input: Bytes,
+    /// Return data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    /// Error, if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Decoded revert reason, if the call reverted with one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    /// Sub calls
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<CallFrame>,
+    /// Logs emitted by this frame, only with `withLog: true`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<CallLogFrame>,
+}
+
+/// Log emitted by a call frame.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CallLogFrame {
+    /// Address
+    pub address: H160,
+    /// Topics
+    pub topics: Vec<H256>,
+    /// Data
+    pub data: Bytes,
+    /// Index of the log among the sub calls of the frame
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<U64>,
+}
+
+/// Account state recorded by the `prestateTracer`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountState {
+    /// Balance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// Nonce
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    /// Code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// Accessed storage slots, missing if none were accessed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<H256, H256>>,
+}
+
+/// Output of the `prestateTracer` with `diffMode: true`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrestateDiffMode {
+    /// State of the touched accounts before the transaction
+    pub pre: BTreeMap<H160, AccountState>,
+    /// State of the modified accounts after the transaction
+    pub post: BTreeMap<H160, AccountState>,
+}
+
+/// Output of the `prestateTracer`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PrestateTraceResult {
+    /// Pre and post states, with `diffMode: true`
+    DiffMode(PrestateDiffMode),
+    /// State of the touched accounts before the transaction
+    Default(BTreeMap<H160, AccountState>),
+}
+
+/// Output of the default struct logger.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTrace {
+    /// Gas used
+    pub gas: u64,
+    /// Whether the transaction failed
+    pub failed: bool,
+    /// Return data, hex encoded without `0x` prefix
+    pub return_value: String,
+    /// Opcode level logs
+    pub struct_logs: Vec<StructLog>,
+}
+
+/// Opcode level log of the default struct logger.
+///
+/// Memory words and storage slots are accepted with or without `0x` prefix
+/// and serialized without it, like geth does.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructLog {
+    /// Program counter
+    pub pc: u64,
+    /// Opcode name
+    pub op: String,
+    /// Remaining gas
+    pub gas: u64,
+    /// Cost of the opcode
+    pub gas_cost: u64,
+    /// Call depth
+    pub depth: u64,
+    /// Stack, missing with `disableStack`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<U256>>,
+    /// Memory words, missing unless `enableMemory`
+    #[serde(
+        default,
+        serialize_with = "serialize_unprefixed_words",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub memory: Option<Vec<H256>>,
+    /// Storage of the current contract, missing with `disableStorage`
+    #[serde(
+        default,
+        serialize_with = "serialize_unprefixed_storage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub storage: Option<BTreeMap<H256, H256>>,
+    /// Return data of the last call, missing unless `enableReturnData`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_data: Option<Bytes>,
+    /// Gas refund counter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund: Option<u64>,
+    /// Error, if the opcode failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn serialize_unprefixed_words<S>(
+    words: &Option<Vec<H256>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match words {
+        Some(words) => serializer.collect_seq(words.iter().map(|word| format!("{:x}", word))),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_unprefixed_storage<S>(
+    storage: &Option<BTreeMap<H256, H256>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match storage {
+        Some(storage) => serializer.collect_map(
+            storage
+                .iter()
+                .map(|(key, value)| (format!("{:x}", key), format!("{:x}", value))),
+        ),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn call_frame_serde() {
+        let s = r#"{"type":"CALL","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","value":"0x0","gas":"0x1c9c380","gasUsed":"0x9f0b","input":"0xa9059cbb","output":"0x08c379a0","error":"execution reverted","revertReason":"transfer failed","calls":[{"type":"STATICCALL","from":"0x5fbdb2315678afecb367f032d93f642f64180aa3","to":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","gas":"0x1b9e9f0","gasUsed":"0x2d1","input":"0x70a08231","output":"0x0000000000000000000000000000000000000000000000000000000000000000"},{"type":"CALL","from":"0x5fbdb2315678afecb367f032d93f642f64180aa3","to":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","value":"0x0","gas":"0x1b9e000","gasUsed":"0x1f4","input":"0x23b872dd","output":"0x","error":"execution reverted","logs":[{"address":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","topics":["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"],"data":"0x","position":"0x0"}]}]}"#;
+        let frame: CallFrame = serde_json::from_str(s).unwrap();
+        assert_eq!(frame.calls.len(), 2);
+        assert_eq!(frame.calls[0].value, None);
+        assert_eq!(frame.calls[1].error.as_deref(), Some("execution reverted"));
+        assert_eq!(frame.calls[1].logs.len(), 1);
+        assert_eq!(frame.revert_reason.as_deref(), Some("transfer failed"));
+        assert_eq!(serde_json::to_string(&frame).unwrap(), s);
+    }
+
+    #[test]
+    fn call_frame_failed_create() {
+        let s = r#"{"type":"CREATE","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","value":"0x0","gas":"0x10000","gasUsed":"0x10000","input":"0x6080","error":"out of gas"}"#;
+        let frame: CallFrame = serde_json::from_str(s).unwrap();
+        assert_eq!(frame.to, None);
+        assert_eq!(frame.output, None);
+        assert_eq!(serde_json::to_string(&frame).unwrap(), s);
+    }
+
+    #[test]
+    fn prestate_default_mode_serde() {
+        let s = r#"{"0x35a9f94af726f07b5162df7e828cc9dc8439e7d0":{"balance":"0x7a48734599f7284","nonce":1133},"0xc8ba32cab1757528daf49033e3673fae77dcf05d":{"balance":"0x0","code":"0x608060","nonce":1,"storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":"0x000000000000000000000000000000000000000000000000000000000024aea6"}}}"#;
+        let result: PrestateTraceResult = serde_json::from_str(s).unwrap();
+        match result {
+            PrestateTraceResult::Default(ref accounts) => {
+                let sender = H160::from_str("35a9f94af726f07b5162df7e828cc9dc8439e7d0").unwrap();
+                assert_eq!(accounts[&sender].nonce, Some(1133));
+                assert_eq!(accounts[&sender].storage, None);
+            }
+            _ => panic!("expected the default mode"),
+        }
+        let expected: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+    }
+
+    #[test]
+    fn prestate_diff_mode_serde() {
+        let s = r#"{"post":{"0x35a9f94af726f07b5162df7e828cc9dc8439e7d0":{"balance":"0x7a48429e177130a","nonce":1134}},"pre":{"0x35a9f94af726f07b5162df7e828cc9dc8439e7d0":{"balance":"0x7a48734599f7284","nonce":1133}}}"#;
+        let result: PrestateTraceResult = serde_json::from_str(s).unwrap();
+        match result {
+            PrestateTraceResult::DiffMode(ref diff) => {
+                assert_eq!(diff.pre.len(), 1);
+                assert_eq!(diff.post.len(), 1);
+            }
+            _ => panic!("expected the diff mode"),
+        }
+        let expected: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+    }
+
+    #[test]
+    fn transaction_trace_serde() {
+        let s = r#"{"gas":21000,"failed":false,"returnValue":"","structLogs":[{"pc":0,"op":"PUSH1","gas":78973,"gasCost":3,"depth":1,"stack":[]},{"pc":2,"op":"MSTORE","gas":78970,"gasCost":12,"depth":1,"stack":["0x80","0x40"],"memory":["0000000000000000000000000000000000000000000000000000000000000000"],"storage":{"0000000000000000000000000000000000000000000000000000000000000000":"000000000000000000000000000000000000000000000000000000000000002a"},"refund":0}]}"#;
+        let trace: TransactionTrace = serde_json::from_str(s).unwrap();
+        assert_eq!(trace.struct_logs.len(), 2);
+        assert_eq!(trace.struct_logs[0].memory, None);
+        assert_eq!(
+            trace.struct_logs[1].stack,
+            Some(vec![U256::from(0x80), U256::from(0x40)])
+        );
+        assert_eq!(trace.struct_logs[1].memory, Some(vec![H256::zero()]));
+        assert_eq!(
+            trace.struct_logs[1].storage.as_ref().unwrap()[&H256::zero()],
+            H256::from_low_u64_be(42)
+        );
+        let serialized = serde_json::to_string(&trace).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransactionTrace>(&serialized).unwrap(),
+            trace
+        );
+    }
+
+    #[test]
+    fn struct_log_geth_output() {
+        // Part of a `debug_traceTransaction` with `enableMemory`, in the
+        // format of geth's struct logger: storing 42 at slot 0 after setting
+        // up the free memory pointer.
+        let s = r#"{"gas":43517,"failed":false,"returnValue":"","structLogs":[{"pc":0,"op":"PUSH1","gas":79000,"gasCost":3,"depth":1,"stack":[],"memory":[]},{"pc":9,"op":"SSTORE","gas":78988,"gasCost":22100,"depth":1,"stack":["0x2a","0x0"],"memory":["0000000000000000000000000000000000000000000000000000000000000000","0000000000000000000000000000000000000000000000000000000000000000","0000000000000000000000000000000000000000000000000000000000000080"],"storage":{"0000000000000000000000000000000000000000000000000000000000000000":"000000000000000000000000000000000000000000000000000000000000002a"},"refund":0},{"pc":10,"op":"STOP","gas":56888,"gasCost":0,"depth":1,"stack":[],"memory":["0000000000000000000000000000000000000000000000000000000000000000","0000000000000000000000000000000000000000000000000000000000000000","0000000000000000000000000000000000000000000000000000000000000080"],"storage":{"0000000000000000000000000000000000000000000000000000000000000000":"000000000000000000000000000000000000000000000000000000000000002a"},"refund":0}]}"#;
+        let trace: TransactionTrace = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            trace.struct_logs[1].memory.as_ref().unwrap()[2],
+            H256::from_low_u64_be(0x80)
+        );
+        assert_eq!(serde_json::to_string(&trace).unwrap(), s);
+
+        // Prefixed words, as sent by other clients, are accepted too.
+        let prefixed = s.replace(r#""0000"#, r#""0x0000"#);
+        assert_ne!(prefixed, s);
+        assert_eq!(
+            serde_json::from_str::<TransactionTrace>(&prefixed).unwrap(),
+            trace
+        );
+    }
+
+    #[test]
+    fn transaction_trace_large() {
+        let log = r#"{"pc":1,"op":"PUSH1","gas":78973,"gasCost":3,"depth":1,"stack":["0x80","0x40","0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"],"memory":["0000000000000000000000000000000000000000000000000000000000000000","0000000000000000000000000000000000000000000000000000000000000080"]}"#;
+        let s = format!(
+            r#"{{"gas":78973,"failed":false,"returnValue":"","structLogs":[{}]}}"#,
+            vec![log; 5000].join(",")
+        );
+        let trace: TransactionTrace = serde_json::from_str(&s).unwrap();
+        assert_eq!(trace.struct_logs.len(), 5000);
+        assert_eq!(
+            trace.struct_logs[4999].stack.as_ref().unwrap()[2],
+            U256::MAX
+        );
+    }
+}
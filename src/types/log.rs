@@ -17,11 +17,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::types::Bytes;
-use ethereum_types::{H160, H256, U256};
-use serde::Serialize;
+use ethereum_types::{Bloom as H2048, BloomInput, H160, H256, U256};
+use serde::{Deserialize, Serialize};
 
 /// Log
-#[derive(Debug, Serialize, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Log {
     /// H160
@@ -46,3 +46,124 @@ pub struct Log {
     #[serde(default)]
     pub removed: bool,
 }
+
+/// Returns the bloom of the given logs, accruing the address and every topic
+/// of each log as defined by the yellow paper.
+pub fn build_bloom<'a>(logs: impl IntoIterator<Item = &'a Log>) -> H2048 {
+    let mut bloom = H2048::zero();
+    for log in logs {
+        bloom.accrue(BloomInput::Raw(log.address.as_bytes()));
+        for topic in &log.topics {
+            bloom.accrue(BloomInput::Raw(topic.as_bytes()));
+        }
+    }
+    bloom
+}
+
+/// Returns whether the bloom may contain a log of the given address with the
+/// given topics. False positives are possible, false negatives are not.
+pub fn bloom_contains(bloom: &H2048, address: &H160, topics: &[H256]) -> bool {
+    bloom.contains_input(BloomInput::Raw(address.as_bytes()))
+        && topics
+            .iter()
+            .all(|topic| bloom.contains_input(BloomInput::Raw(topic.as_bytes())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+    const APPROVAL: &str = "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    const WETH: &str = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+
+    fn log(address: &str, topics: &[&str]) -> Log {
+        Log {
+            address: address.parse().unwrap(),
+            topics: topics.iter().map(|topic| topic.parse().unwrap()).collect(),
+            data: Bytes::default(),
+            block_hash: None,
+            block_number: None,
+            transaction_hash: None,
+            transaction_index: None,
+            log_index: None,
+            transaction_log_index: None,
+            removed: false,
+        }
+    }
+
+    fn topic(address: &str) -> String {
+        format!("0x000000000000000000000000{}", &address[2..])
+    }
+
+    #[test]
+    fn build_bloom_empty() {
+        assert_eq!(build_bloom(&[]), H2048::zero());
+    }
+
+    #[test]
+    fn build_bloom_logs() {
+        let from = topic("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+        let to = topic("0x70997970c51812dc3a010c7d01b50e0d17dc79c8");
+        let transfer = log(USDC, &[TRANSFER, &from, &to]);
+        let approval = log(WETH, &[APPROVAL, &from, &to]);
+
+        // Computed with an independent implementation of the yellow paper.
+        let expected: H2048 = "0x00000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000008000000000000000000000000000000000000000000000800000000000000000100000000000000000000000000000010000000000000000000000000000000000000000000000000010000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000002000000200000000000000000000000002000000000000000000000000000000000000000000000000000000001000000000000000000000000000000".parse().unwrap();
+        assert_eq!(build_bloom([&transfer]), expected);
+
+        let expected: H2048 = "0x00000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000002000000080000000000000000200000000000000000000008000008000000000000000000000000000000000000000000000800000000000000000100000000000000000000000000000010000000000000000000000000000000000000000000000000010000000000000000000000020000000000200000000000000000000000000000000000000000000000000000000002000000200000000000000000000000002000000000000000000000000010200000000000000000000000000001000000000000000000000000000000".parse().unwrap();
+        let bloom = build_bloom(&[transfer, approval]);
+        assert_eq!(bloom, expected);
+    }
+
+    #[test]
+    fn build_bloom_reference_vector() {
+        // The reference log and bloom of the `ethbloom` documentation.
+        let bloom = build_bloom([&log(
+            "0xef2d6d194084c2de36e0dabfce45d046b37d1106",
+            &["0x02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc"],
+        )]);
+        let expected: H2048 = concat!(
+            "0x00000000000000000000000000000000",
+            "00000000100000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000002020000000000000000000000",
+            "00000000000000000000000800000000",
+            "10000000000000000000000000000000",
+            "00000000000000000000001000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(bloom, expected);
+    }
+
+    #[test]
+    fn bloom_contains_logs() {
+        let from = topic("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+        let bloom = build_bloom(&[log(USDC, &[TRANSFER, &from])]);
+        let usdc: H160 = USDC.parse().unwrap();
+        let transfer: H256 = TRANSFER.parse().unwrap();
+
+        assert!(bloom_contains(&bloom, &usdc, &[]));
+        assert!(bloom_contains(&bloom, &usdc, &[transfer]));
+        assert!(bloom_contains(
+            &bloom,
+            &usdc,
+            &[transfer, from.parse().unwrap()]
+        ));
+        assert!(!bloom_contains(&bloom, &WETH.parse().unwrap(), &[transfer]));
+        assert!(!bloom_contains(&bloom, &usdc, &[APPROVAL.parse().unwrap()]));
+        assert!(!bloom_contains(&H2048::zero(), &usdc, &[]));
+    }
+}
@@ -18,43 +18,96 @@
 
 //! RPC types
 
+mod access_list;
 mod account_info;
 mod block;
 mod block_number;
+mod bundle;
 mod bytes;
 mod call_request;
+mod debug;
+mod eip712;
+mod fee;
 mod filter;
+mod geth_tracer;
 mod index;
 mod log;
+mod overrides;
+mod quantity;
 mod receipt;
+mod simulate;
 mod sync;
+mod system;
+mod trace;
 mod transaction;
+#[cfg(feature = "ethereum")]
+mod transaction_message;
 mod transaction_request;
+mod txpool;
 mod work;
 
+pub mod admin;
 pub mod pubsub;
 
+pub use self::access_list::{AccessList, AccessListItem, AccessListResult};
 pub use self::account_info::{
-    AccountInfo, EthAccount, ExtAccountInfo, RecoveredAccount, StorageProof,
+    Account, AccountInfo, EIP1186ProofResponse, EthAccount, ExtAccountInfo, RecoveredAccount,
+    StorageProof,
 };
 pub use self::block::{Block, BlockTransactions, Header, Rich, RichBlock, RichHeader};
 pub use self::block_number::BlockNumber;
+pub use self::bundle::{Bundle, EthCallResponse, StateContext};
 pub use self::bytes::Bytes;
-pub use self::call_request::CallRequest;
+pub use self::call_request::{CallRequest, CallRequestBuilder};
+pub use self::debug::{
+    AccountRangeEntry, AccountRangeResult, BadBlock, GethDebugBuiltInTracerType,
+    GethDebugTracerType, GethDebugTracingCallOptions, GethDebugTracingOptions, StorageEntry,
+    StorageRangeResult, TxTraceResult,
+};
+pub use self::eip712::{EIP712Domain, FieldType, TypedData};
+pub use self::fee::FeeHistory;
 pub use self::filter::{
     Filter, FilterAddress, FilterChanges, FilterPool, FilterPoolItem, FilterType, FilteredParams,
     Topic, VariadicValue,
 };
+pub use self::geth_tracer::{
+    AccountState, CallFrame, CallLogFrame, PrestateDiffMode, PrestateTraceResult, StructLog,
+    TransactionTrace,
+};
 pub use self::index::Index;
-pub use self::log::Log;
+pub use self::log::{bloom_contains, build_bloom, Log};
+pub use self::overrides::{AccountOverride, BlockOverrides, CallStateOverride};
+pub use self::pubsub::Result as PubSubResult;
+pub use self::quantity::{
+    deserialize_optional_u256_flexible, deserialize_u256_flexible, DevTime, Quantity,
+    SignedQuantity,
+};
 pub use self::receipt::Receipt;
+pub use self::simulate::{SimBlock, SimCallResult, SimulateError, SimulatePayload, SimulatedBlock};
 pub use self::sync::{
     ChainStatus, EthProtocolInfo, PeerCount, PeerInfo, PeerNetworkInfo, PeerProtocolsInfo, Peers,
     PipProtocolInfo, SyncInfo, SyncStatus, TransactionStats,
 };
+pub use self::system::Health;
+pub use self::trace::{
+    AccountDiff, Action, Call, CallResult, CallType, ChangedType, Create, CreateResult, Delta,
+    FlatTrace, LocalizedTrace, MemoryDiff, Res, Reward, RewardType, StateDiff, StorageDiff,
+    Suicide, TraceFilter, TraceResults, TraceResultsWithTransactionHash, TraceType,
+    VMExecutedOperation, VMOperation, VMTrace,
+};
+#[cfg(feature = "parity-compat")]
+pub use self::transaction::TransactionCondition;
 pub use self::transaction::{
     LocalTransactionStatus, PendingTransaction, PendingTransactions, RichRawTransaction,
     Transaction,
 };
-pub use self::transaction_request::TransactionRequest;
+#[cfg(feature = "ethereum")]
+pub use self::transaction_message::{FillDefaults, TransactionMessage};
+pub use self::transaction_request::{
+    FeeMode, RequestValidationError, TransactionKind, TransactionRequest,
+};
+pub use self::txpool::{
+    TxPoolContent, TxPoolContentEntry, TxPoolContentFrom, TxPoolEntry, TxPoolInspect,
+    TxPoolInspectSummary, TxPoolResult, TxPoolStatus,
+};
 pub use self::work::Work;
@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! State and block overrides of simulated calls.
+
+use std::collections::BTreeMap;
+
+use ethereum_types::{H160, H256, U256, U64};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::quantity::deserialize_optional_u256_flexible;
+use crate::types::Bytes;
+
+/// Overrides of an account's state for a simulated call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOverride {
+    /// Balance
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// Nonce
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<U64>,
+    /// Code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// Storage replacing the whole account storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<BTreeMap<H256, H256>>,
+    /// Storage slots replacing the ones of the account storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_diff: Option<BTreeMap<H256, H256>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAccountOverride {
+    balance: Option<U256>,
+    nonce: Option<U64>,
+    code: Option<Bytes>,
+    state: Option<BTreeMap<H256, H256>>,
+    state_diff: Option<BTreeMap<H256, H256>>,
+}
+
+impl<'a> Deserialize<'a> for AccountOverride {
+    fn deserialize<D>(deserializer: D) -> Result<AccountOverride, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let raw = RawAccountOverride::deserialize(deserializer)?;
+        if raw.state.is_some() && raw.state_diff.is_some() {
+            return Err(D::Error::custom(
+                "Invalid account override: state and stateDiff are mutually exclusive",
+            ));
+        }
+
+        Ok(AccountOverride {
+            balance: raw.balance,
+            nonce: raw.nonce,
+            code: raw.code,
+            state: raw.state,
+            state_diff: raw.state_diff,
+        })
+    }
+}
+
+/// Overrides of the state for a simulated call, by account.
+pub type CallStateOverride = BTreeMap<H160, AccountOverride>;
+
+/// Overrides of the block context for a simulated call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockOverrides {
+    /// Block number
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub number: Option<U256>,
+    /// Difficulty
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub difficulty: Option<U256>,
+    /// Timestamp
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub time: Option<U256>,
+    /// Gas limit
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gas_limit: Option<U256>,
+    /// Block author
+    #[serde(default, alias = "coinbase", skip_serializing_if = "Option::is_none")]
+    pub fee_recipient: Option<H160>,
+    /// Randomness beacon output of the previous block
+    #[serde(default, alias = "random", skip_serializing_if = "Option::is_none")]
+    pub prev_randao: Option<H256>,
+    /// Base fee per gas
+    #[serde(
+        default,
+        alias = "baseFee",
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub base_fee_per_gas: Option<U256>,
+    /// Base fee per blob gas
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_u256_flexible",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blob_base_fee: Option<U256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_override_exclusive_state() {
+        let res: Result<AccountOverride, _> =
+            serde_json::from_str(r#"{"state":{},"stateDiff":{}}"#);
+        assert!(res.is_err());
+        let over: AccountOverride =
+            serde_json::from_str(r#"{"balance":"0x1","stateDiff":{}}"#).unwrap();
+        assert_eq!(over.balance, Some(U256::one()));
+        assert_eq!(over.state_diff, Some(BTreeMap::new()));
+        assert_eq!(
+            serde_json::to_string(&over).unwrap(),
+            r#"{"balance":"0x1","stateDiff":{}}"#
+        );
+    }
+
+    #[test]
+    fn block_overrides_serde() {
+        assert_eq!(
+            serde_json::to_string(&BlockOverrides::default()).unwrap(),
+            "{}"
+        );
+        let over = BlockOverrides {
+            number: Some(U256::from(20_000_000)),
+            difficulty: None,
+            time: Some(U256::from(1_722_000_000)),
+            gas_limit: Some(U256::from(30_000_000)),
+            fee_recipient: Some(H160::repeat_byte(0x11)),
+            prev_randao: Some(H256::repeat_byte(0x22)),
+            base_fee_per_gas: Some(U256::from(7)),
+            blob_base_fee: None,
+        };
+        let serialized = serde_json::to_string(&over).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"number":"0x1312d00","time":"0x66a3a280","gasLimit":"0x1c9c380","feeRecipient":"0x1111111111111111111111111111111111111111","prevRandao":"0x2222222222222222222222222222222222222222222222222222222222222222","baseFeePerGas":"0x7"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<BlockOverrides>(&serialized).unwrap(),
+            over
+        );
+    }
+
+    #[test]
+    fn block_overrides_tenderly() {
+        // Block overrides of a Tenderly simulation, with a decimal timestamp
+        // and the legacy field names.
+        let over: BlockOverrides = serde_json::from_str(
+            r#"{
+                "number": "0x1312d00",
+                "time": 1722000000,
+                "gasLimit": "0x1c9c380",
+                "coinbase": "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5",
+                "random": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "baseFee": "0x3b9aca00"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            over,
+            BlockOverrides {
+                number: Some(U256::from(20_000_000)),
+                difficulty: None,
+                time: Some(U256::from(1_722_000_000)),
+                gas_limit: Some(U256::from(30_000_000)),
+                fee_recipient: Some(
+                    "0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5"
+                        .parse()
+                        .unwrap()
+                ),
+                prev_randao: Some(H256::from_low_u64_be(1)),
+                base_fee_per_gas: Some(U256::from(1_000_000_000)),
+                blob_base_fee: None,
+            }
+        );
+        let over: BlockOverrides = serde_json::from_str(r#"{"time":"1722000000"}"#).unwrap();
+        assert_eq!(over.time, Some(U256::from(1_722_000_000)));
+    }
+}
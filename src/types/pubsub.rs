@@ -18,7 +18,7 @@
 
 //! Pub-Sub types.
 
-use crate::types::{Filter, Log, RichHeader};
+use crate::types::{Filter, Header, Log, Transaction};
 use ethereum_types::H256;
 use futures::channel::mpsc;
 use jsonrpc_pubsub::{PubSubMetadata, Session};
@@ -31,21 +31,88 @@ use std::sync::Arc;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Result {
     /// New block header.
-    Header(Box<RichHeader>),
+    Header(Box<Header>),
     /// Log
     Log(Box<Log>),
     /// Transaction hash
     TransactionHash(H256),
+    /// Full pending transaction
+    FullTransaction(Box<Transaction>),
     /// SyncStatus
     SyncState(PubSubSyncStatus),
 }
 
-/// PubSbub sync status
-#[derive(Debug, Serialize, Eq, PartialEq, Clone)]
+/// PubSub sync status.
+///
+/// Serialized as `{"syncing": true, "status": {...}}` while syncing and as a
+/// bare `false` once sync is done.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum PubSubSyncStatus {
+    /// Sync in progress
+    Syncing(PubSubSyncProgress),
+    /// Not syncing
+    NotSyncing,
+}
+
+/// Sync progress of a `syncing` notification.
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct PubSubSyncStatus {
-    /// is_major_syncing?
-    pub syncing: bool,
+pub struct PubSubSyncProgress {
+    /// Starting block
+    pub starting_block: u64,
+    /// Current block
+    pub current_block: u64,
+    /// Highest block seen so far
+    pub highest_block: u64,
+    /// Number of state entries pulled so far
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pulled_states: Option<u64>,
+    /// Number of known state entries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub known_states: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PubSubSyncing<T> {
+    syncing: bool,
+    status: Option<T>,
+}
+
+impl Serialize for PubSubSyncStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            PubSubSyncStatus::Syncing(ref progress) => PubSubSyncing {
+                syncing: true,
+                status: Some(progress),
+            }
+            .serialize(serializer),
+            PubSubSyncStatus::NotSyncing => false.serialize(serializer),
+        }
+    }
+}
+
+impl<'a> Deserialize<'a> for PubSubSyncStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PubSubSyncStatus, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let v: Value = Deserialize::deserialize(deserializer)?;
+
+        if v == Value::Bool(false) {
+            return Ok(PubSubSyncStatus::NotSyncing);
+        }
+
+        let syncing: PubSubSyncing<PubSubSyncProgress> =
+            from_value(v).map_err(|e| D::Error::custom(format!("Invalid sync status: {}", e)))?;
+        match (syncing.syncing, syncing.status) {
+            (true, Some(progress)) => Ok(PubSubSyncStatus::Syncing(progress)),
+            (false, _) => Ok(PubSubSyncStatus::NotSyncing),
+            (true, None) => Err(D::Error::custom("Invalid sync status: missing status")),
+        }
+    }
 }
 
 impl Serialize for Result {
@@ -57,11 +124,29 @@ impl Serialize for Result {
             Result::Header(ref header) => header.serialize(serializer),
             Result::Log(ref log) => log.serialize(serializer),
             Result::TransactionHash(ref hash) => hash.serialize(serializer),
+            Result::FullTransaction(ref transaction) => transaction.serialize(serializer),
             Result::SyncState(ref sync) => sync.serialize(serializer),
         }
     }
 }
 
+impl<'a> Deserialize<'a> for Result {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Result, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let v: Value = Deserialize::deserialize(deserializer)?;
+
+        from_value(v.clone())
+            .map(Result::TransactionHash)
+            .or_else(|_| from_value(v.clone()).map(Result::SyncState))
+            .or_else(|_| from_value(v.clone()).map(|log| Result::Log(Box::new(log))))
+            .or_else(|_| from_value(v.clone()).map(|header| Result::Header(Box::new(header))))
+            .or_else(|_| from_value(v).map(|tx| Result::FullTransaction(Box::new(tx))))
+            .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub result: {}", e)))
+    }
+}
+
 /// Subscription kind.
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
 #[serde(deny_unknown_fields)]
@@ -78,18 +163,15 @@ pub enum Kind {
 }
 
 /// Subscription kind.
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
 pub enum Params {
     /// No parameters passed.
+    #[default]
     None,
     /// Log parameters.
-    Logs(Filter),
-}
-
-impl Default for Params {
-    fn default() -> Self {
-        Params::None
-    }
+    Logs(Box<Filter>),
+    /// Whether `newPendingTransactions` should stream full transactions.
+    TransactionDetails(bool),
 }
 
 impl<'a> Deserialize<'a> for Params {
@@ -103,8 +185,12 @@ impl<'a> Deserialize<'a> for Params {
             return Ok(Params::None);
         }
 
+        if let Value::Bool(full) = v {
+            return Ok(Params::TransactionDetails(full));
+        }
+
         from_value(v)
-            .map(Params::Logs)
+            .map(|filter| Params::Logs(Box::new(filter)))
             .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub parameters: {}", e)))
     }
 }
@@ -147,3 +233,209 @@ impl From<mpsc::UnboundedSender<String>> for Metadata {
         Self::new(sender)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::VariadicValue;
+    use ethereum_types::H160;
+    use std::str::FromStr;
+
+    fn assert_round_trip(json: &str) -> Result {
+        let result: Result = serde_json::from_str(json).unwrap();
+        let expected: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+        result
+    }
+
+    #[test]
+    fn result_header_serde() {
+        let result = assert_round_trip(
+            r#"{"author":"0x0000000000000000000000000000000000000000","difficulty":"0x0","extraData":"0x","gasLimit":"0x1c9c380","gasUsed":"0x5208","hash":"0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","miner":"0x0000000000000000000000000000000000000000","number":"0x1b4","parentHash":"0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50","receiptsRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","sealFields":[],"sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","size":"0x220","stateRoot":"0xd5855eb08b3387c0af375e9cdb6acfc05eb8f519e419b874b6ff2ffda7ed1dff","timestamp":"0x55ba467c","transactionsRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"}"#,
+        );
+        assert!(matches!(result, Result::Header(_)));
+    }
+
+    #[test]
+    fn result_london_header_serde() {
+        let result = assert_round_trip(
+            r#"{"author":"0x0000000000000000000000000000000000000000","baseFeePerGas":"0x7","difficulty":"0x0","extraData":"0x","gasLimit":"0x1c9c380","gasUsed":"0x5208","hash":"0x7c5a35e9cb3e8ae0e221ab470abae9d446c3a5626ce6689fc777dcffcab52c70","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","miner":"0x0000000000000000000000000000000000000000","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","number":"0x1b4","parentHash":"0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50","receiptsRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421","sealFields":[],"sha3Uncles":"0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347","size":"0x220","stateRoot":"0xd5855eb08b3387c0af375e9cdb6acfc05eb8f519e419b874b6ff2ffda7ed1dff","timestamp":"0x55ba467c","transactionsRoot":"0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"}"#,
+        );
+        match result {
+            Result::Header(header) => {
+                assert_eq!(header.base_fee_per_gas, Some(7.into()));
+                assert_eq!(header.nonce, Some(Default::default()));
+            }
+            _ => panic!("expected a header"),
+        }
+    }
+
+    #[test]
+    fn geth_new_heads_notification() {
+        // geth sends headers without `author` and `sealFields`; this one is
+        // the mainnet genesis header.
+        let notification: Value = serde_json::from_str(r#"{
+            "jsonrpc": "2.0",
+            "method": "eth_subscription",
+            "params": {
+                "subscription": "0xcd0c3e8af590364c09d0fa6a1210faf5",
+                "result": {
+                    "difficulty": "0x400000000",
+                    "extraData": "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa",
+                    "gasLimit": "0x1388",
+                    "gasUsed": "0x0",
+                    "hash": "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                    "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                    "miner": "0x0000000000000000000000000000000000000000",
+                    "mixHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "nonce": "0x0000000000000042",
+                    "number": "0x0",
+                    "parentHash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "receiptsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+                    "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+                    "stateRoot": "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544",
+                    "timestamp": "0x0",
+                    "transactionsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421"
+                }
+            }
+        }"#)
+        .unwrap();
+        let result: Result = from_value(notification["params"]["result"].clone()).unwrap();
+        match result {
+            Result::Header(header) => {
+                assert_eq!(header.number, Some(0.into()));
+                assert_eq!(header.gas_limit, 5000.into());
+                assert_eq!(
+                    header.state_root,
+                    H256::from_str(
+                        "d7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544"
+                    )
+                    .unwrap()
+                );
+            }
+            _ => panic!("expected a header"),
+        }
+    }
+
+    #[test]
+    fn result_log_serde() {
+        let result = assert_round_trip(
+            r#"{"address":"0x8320fe7702b96808f7bbc0d4a888ed1468216cfd","blockHash":"0x61cdb2a09ab99abf791d474f20c2ea89bf8de2923a2d42bb49944c8c993cbf04","blockNumber":"0x29e87","data":"0x00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000003","logIndex":"0x0","topics":["0xd78a0cb8bb633d06981248b816e7bd33c2a35a6089241d099fa519e361cab902"],"transactionHash":"0xe044554a0a55067caafd07f8020ab9f2af60bdfe337e395ecd84b4877a3d1ab4","transactionIndex":"0x0","transactionLogIndex":null,"removed":false}"#,
+        );
+        assert!(matches!(result, Result::Log(_)));
+    }
+
+    #[test]
+    fn result_transaction_hash_serde() {
+        let result = assert_round_trip(
+            r#""0xd6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa""#,
+        );
+        assert!(matches!(result, Result::TransactionHash(_)));
+    }
+
+    #[test]
+    fn result_sync_state_serde() {
+        // Start and stop notifications of a geth WebSocket session.
+        let result = assert_round_trip(
+            r#"{"syncing":true,"status":{"startingBlock":674427,"currentBlock":67400,"highestBlock":674432,"pulledStates":0,"knownStates":0}}"#,
+        );
+        assert_eq!(
+            result,
+            Result::SyncState(PubSubSyncStatus::Syncing(PubSubSyncProgress {
+                starting_block: 674427,
+                current_block: 67400,
+                highest_block: 674432,
+                pulled_states: Some(0),
+                known_states: Some(0),
+            }))
+        );
+
+        let result = assert_round_trip("false");
+        assert_eq!(result, Result::SyncState(PubSubSyncStatus::NotSyncing));
+    }
+
+    #[test]
+    fn kind_serde() {
+        let kinds: Vec<Kind> =
+            serde_json::from_str(r#"["newHeads","logs","newPendingTransactions","syncing"]"#)
+                .unwrap();
+        assert_eq!(
+            kinds,
+            vec![
+                Kind::NewHeads,
+                Kind::Logs,
+                Kind::NewPendingTransactions,
+                Kind::Syncing
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&kinds).unwrap(),
+            r#"["newHeads","logs","newPendingTransactions","syncing"]"#
+        );
+    }
+
+    #[test]
+    fn unknown_kind_is_an_error() {
+        let kind: ::std::result::Result<Kind, _> = serde_json::from_str(r#""newBlocks""#);
+        assert!(kind.is_err());
+    }
+
+    #[test]
+    fn params_deserialize() {
+        let none: Params = serde_json::from_str("null").unwrap();
+        assert_eq!(none, Params::None);
+
+        // As sent by web3.js and ethers.js for `eth_subscribe("logs", {...})`.
+        let logs: Params = serde_json::from_str(
+            r#"{"address":"0x8320fe7702b96808f7bbc0d4a888ed1468216cfd","topics":["0xd78a0cb8bb633d06981248b816e7bd33c2a35a6089241d099fa519e361cab902"]}"#,
+        )
+        .unwrap();
+        let address = H160::from_str("8320fe7702b96808f7bbc0d4a888ed1468216cfd").unwrap();
+        let topic =
+            H256::from_str("d78a0cb8bb633d06981248b816e7bd33c2a35a6089241d099fa519e361cab902")
+                .unwrap();
+        assert_eq!(
+            logs,
+            Params::Logs(Box::new(Filter {
+                from_block: None,
+                to_block: None,
+                block_hash: None,
+                address: Some(VariadicValue::Single(address)),
+                topics: Some(VariadicValue::Single(Some(VariadicValue::Multiple(vec![
+                    Some(topic)
+                ])))),
+                limit: None,
+            }))
+        );
+
+        let invalid: ::std::result::Result<Params, _> = serde_json::from_str(r#"{"foo":1}"#);
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn params_transaction_details() {
+        let full: Params = serde_json::from_str("true").unwrap();
+        assert_eq!(full, Params::TransactionDetails(true));
+        let hashes: Params = serde_json::from_str("false").unwrap();
+        assert_eq!(hashes, Params::TransactionDetails(false));
+    }
+
+    #[test]
+    fn result_full_transaction_serde() {
+        let json = r#"{"blockHash":null,"blockNumber":null,"from":"0x407d73d8a49eeb85d32cf465507dd71d507100c1","gas":"0x76c0","gasPrice":"0x9184e72a000","hash":"0xd6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa","input":"0x","nonce":"0x1","to":"0x853f43d8a49eeb85d32cf465507dd71d507100c1","transactionIndex":null,"value":"0x7f110","v":"0x25","r":"0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea","s":"0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c"}"#;
+        let result: Result = serde_json::from_str(json).unwrap();
+        match result {
+            Result::FullTransaction(ref tx) => {
+                assert_eq!(tx.nonce, 1.into());
+                assert_eq!(tx.block_hash, None);
+            }
+            _ => panic!("expected a full transaction"),
+        }
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            value["hash"],
+            "0xd6fdc5cc41a9959e922f30cb772a9aef46f4daea279307bc5f7024edc4ccd7fa"
+        );
+        assert_eq!(value["blockHash"], Value::Null);
+    }
+}
@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Quantities accepted from clients in several encodings.
+
+use ethereum_types::{U256, U64};
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Represents U256, deserialized from a hex string, a decimal string or a
+/// JSON number, and serialized as a hex string.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quantity(U256);
+
+impl Quantity {
+    /// Convert to U256
+    pub fn value(&self) -> U256 {
+        self.0
+    }
+}
+
+impl From<U256> for Quantity {
+    fn from(value: U256) -> Quantity {
+        Quantity(value)
+    }
+}
+
+impl From<Quantity> for U256 {
+    fn from(quantity: Quantity) -> U256 {
+        quantity.0
+    }
+}
+
+impl Serialize for Quantity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'a> Deserialize<'a> for Quantity {
+    fn deserialize<D>(deserializer: D) -> Result<Quantity, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        deserializer.deserialize_any(QuantityVisitor)
+    }
+}
+
+/// Deserializes a U256 from a hex string, a decimal string or a JSON
+/// number, for use with `#[serde(deserialize_with)]`.
+pub fn deserialize_u256_flexible<'a, D>(deserializer: D) -> Result<U256, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    Ok(Quantity::deserialize(deserializer)?.0)
+}
+
+/// Deserializes an optional U256 like `deserialize_u256_flexible`. Fields
+/// using it also need `#[serde(default)]`.
+pub fn deserialize_optional_u256_flexible<'a, D>(deserializer: D) -> Result<Option<U256>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    Ok(Option::<Quantity>::deserialize(deserializer)?.map(|quantity| quantity.0))
+}
+
+/// Represents i64, deserialized like `Quantity` but allowing a leading `-`,
+/// and serialized as a JSON number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedQuantity(i64);
+
+impl SignedQuantity {
+    /// Convert to i64
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+}
+
+impl From<i64> for SignedQuantity {
+    fn from(value: i64) -> SignedQuantity {
+        SignedQuantity(value)
+    }
+}
+
+impl From<SignedQuantity> for i64 {
+    fn from(quantity: SignedQuantity) -> i64 {
+        quantity.0
+    }
+}
+
+impl Serialize for SignedQuantity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(self.0)
+    }
+}
+
+impl<'a> Deserialize<'a> for SignedQuantity {
+    fn deserialize<D>(deserializer: D) -> Result<SignedQuantity, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        deserializer.deserialize_any(SignedQuantityVisitor)
+    }
+}
+
+/// Deserializes an optional U64 from any encoding accepted by `Quantity`.
+pub(crate) fn deserialize_optional_u64_quantity<'a, D>(
+    deserializer: D,
+) -> Result<Option<U64>, D::Error>
+where
+    D: Deserializer<'a>,
+{
+    match Option::<Quantity>::deserialize(deserializer)? {
+        Some(quantity) if quantity.0 > U256::from(u64::MAX) => Err(D::Error::custom(format!(
+            "Invalid quantity: {:#x} out of range",
+            quantity.0
+        ))),
+        Some(quantity) => Ok(Some(U64::from(quantity.0.low_u64()))),
+        None => Ok(None),
+    }
+}
+
+struct QuantityVisitor;
+
+impl<'a> Visitor<'a> for QuantityVisitor {
+    type Value = Quantity;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a hex-encoded or decimal quantity")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match value.strip_prefix("0x") {
+            Some("") => Err(Error::custom("Invalid quantity: empty hex string")),
+            None if value.is_empty() => Err(Error::custom("Invalid quantity: empty string")),
+            Some(hex) => U256::from_str_radix(hex, 16)
+                .map(Quantity)
+                .map_err(|e| Error::custom(format!("Invalid quantity: {}", e))),
+            None => U256::from_dec_str(value)
+                .map(Quantity)
+                .map_err(|e| Error::custom(format!("Invalid quantity: {:?}", e))),
+        }
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(value.as_ref())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Quantity(U256::from(value)))
+    }
+}
+
+struct SignedQuantityVisitor;
+
+impl<'a> Visitor<'a> for SignedQuantityVisitor {
+    type Value = SignedQuantity;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a signed hex-encoded or decimal quantity")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let (negative, magnitude) = match value.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, value),
+        };
+        let magnitude = QuantityVisitor.visit_str::<E>(magnitude)?.0;
+        if magnitude > U256::from(i64::MAX as u64) + U256::from(negative as u64) {
+            return Err(Error::custom(format!(
+                "Invalid quantity: {} out of range",
+                value
+            )));
+        }
+        let magnitude = magnitude.low_u64();
+        Ok(SignedQuantity(if negative {
+            (magnitude as i64).wrapping_neg()
+        } else {
+            magnitude as i64
+        }))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(value.as_ref())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        i64::try_from(value)
+            .map(SignedQuantity)
+            .map_err(|_| Error::custom(format!("Invalid quantity: {} out of range", value)))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(SignedQuantity(value))
+    }
+}
+
+/// Seconds returned by the dev time methods, in the shape of the tool the
+/// node emulates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DevTime {
+    /// Serialized as a JSON number, like ganache and anvil
+    Ganache(i64),
+    /// Serialized as a decimal string, like Hardhat
+    Hardhat(i64),
+}
+
+impl DevTime {
+    /// Convert to i64
+    pub fn value(&self) -> i64 {
+        match *self {
+            DevTime::Ganache(value) | DevTime::Hardhat(value) => value,
+        }
+    }
+}
+
+impl Serialize for DevTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            DevTime::Ganache(value) => serializer.serialize_i64(value),
+            DevTime::Hardhat(value) => serializer.collect_str(&value),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDevTime {
+    Number(i64),
+    Decimal(String),
+}
+
+impl<'a> Deserialize<'a> for DevTime {
+    fn deserialize<D>(deserializer: D) -> Result<DevTime, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        match RawDevTime::deserialize(deserializer)? {
+            RawDevTime::Number(value) => Ok(DevTime::Ganache(value)),
+            RawDevTime::Decimal(value) => value
+                .parse()
+                .map(DevTime::Hardhat)
+                .map_err(|e| D::Error::custom(format!("Invalid time {}: {}", value, e))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dev_time_serde() {
+        assert_eq!(serde_json::to_string(&DevTime::Ganache(60)).unwrap(), "60");
+        assert_eq!(
+            serde_json::to_string(&DevTime::Hardhat(60)).unwrap(),
+            r#""60""#
+        );
+        assert_eq!(
+            serde_json::to_string(&DevTime::Hardhat(-60)).unwrap(),
+            r#""-60""#
+        );
+        assert_eq!(
+            serde_json::from_str::<DevTime>("60").unwrap(),
+            DevTime::Ganache(60)
+        );
+        assert_eq!(
+            serde_json::from_str::<DevTime>(r#""60""#).unwrap(),
+            DevTime::Hardhat(60)
+        );
+        assert!(serde_json::from_str::<DevTime>(r#""0x3c""#).is_err());
+    }
+
+    #[test]
+    fn quantity_deserialize() {
+        let s = r#"["0x0", "0x1a", "0X1a", "26", 26, "0x", "-1", -1, 1.5, "115792089237316195423570985008687907853269984665640564039457584007913129639935", ""]"#;
+        let deserialized: Vec<serde_json::Value> = serde_json::from_str(s).unwrap();
+        let parsed: Vec<Result<Quantity, _>> = deserialized
+            .into_iter()
+            .map(serde_json::from_value::<Quantity>)
+            .collect();
+
+        assert_eq!(parsed[0].as_ref().unwrap(), &Quantity(U256::zero()));
+        assert_eq!(parsed[1].as_ref().unwrap(), &Quantity(U256::from(26)));
+        assert!(parsed[2].is_err());
+        assert_eq!(parsed[3].as_ref().unwrap(), &Quantity(U256::from(26)));
+        assert_eq!(parsed[4].as_ref().unwrap(), &Quantity(U256::from(26)));
+        assert!(parsed[5].is_err());
+        assert!(parsed[6].is_err());
+        assert!(parsed[7].is_err());
+        assert!(parsed[8].is_err());
+        assert_eq!(parsed[9].as_ref().unwrap(), &Quantity(U256::MAX));
+        assert!(parsed[10].is_err());
+    }
+
+    #[test]
+    fn quantity_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Quantity(U256::from(26))).unwrap(),
+            r#""0x1a""#
+        );
+    }
+
+    #[test]
+    fn signed_quantity_deserialize() {
+        let s = r#"[60, -60, "0x3c", "-0x3c", "-60", "--60", "0x", 1.5, "0x8000000000000000", "-0x8000000000000000", 9223372036854775808, "", "-"]"#;
+        let deserialized: Vec<serde_json::Value> = serde_json::from_str(s).unwrap();
+        let parsed: Vec<Result<SignedQuantity, _>> = deserialized
+            .into_iter()
+            .map(serde_json::from_value::<SignedQuantity>)
+            .collect();
+
+        assert_eq!(parsed[0].as_ref().unwrap(), &SignedQuantity(60));
+        assert_eq!(parsed[1].as_ref().unwrap(), &SignedQuantity(-60));
+        assert_eq!(parsed[2].as_ref().unwrap(), &SignedQuantity(60));
+        assert_eq!(parsed[3].as_ref().unwrap(), &SignedQuantity(-60));
+        assert_eq!(parsed[4].as_ref().unwrap(), &SignedQuantity(-60));
+        assert!(parsed[5].is_err());
+        assert!(parsed[6].is_err());
+        assert!(parsed[7].is_err());
+        assert!(parsed[8].is_err());
+        assert_eq!(parsed[9].as_ref().unwrap(), &SignedQuantity(i64::MIN));
+        assert!(parsed[10].is_err());
+        assert!(parsed[11].is_err());
+        assert!(parsed[12].is_err());
+    }
+}
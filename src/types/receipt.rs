@@ -18,10 +18,14 @@
 
 use crate::types::Log;
 use ethereum_types::{Bloom as H2048, H160, H256, U256, U64};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Receipt
-#[derive(Debug, Serialize)]
+///
+/// Exactly one of `state_root`, for receipts predating Byzantium, and
+/// `status_code` is set. Responses carrying both are rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawReceipt")]
 #[serde(rename_all = "camelCase")]
 pub struct Receipt {
     /// Transaction Hash
@@ -32,7 +36,7 @@ pub struct Receipt {
     pub block_hash: Option<H256>,
     /// Sender
     pub from: Option<H160>,
-    /// Recipient
+    /// Recipient, `null` for a contract creation
     pub to: Option<H160>,
     /// Block number
     pub block_number: Option<U256>,
@@ -40,7 +44,11 @@ pub struct Receipt {
     pub cumulative_gas_used: U256,
     /// Gas used
     pub gas_used: Option<U256>,
-    /// Contract address
+    /// Price per gas paid by the transaction, its gas price for a legacy
+    /// transaction. Missing in responses of nodes predating London.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_gas_price: Option<U256>,
+    /// Created contract address, `null` unless a contract creation
     pub contract_address: Option<H160>,
     /// Logs
     pub logs: Vec<Log>,
@@ -54,4 +62,437 @@ pub struct Receipt {
     // NOTE(niklasad1): Unknown after EIP98 rules, if it's missing then skip serializing it
     #[serde(skip_serializing_if = "Option::is_none", rename = "status")]
     pub status_code: Option<U64>,
+    /// EIP-2718 type of the transaction
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<U64>,
+    /// Blob gas used by a blob transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_gas_used: Option<U64>,
+    /// Price per blob gas paid by a blob transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_gas_price: Option<U256>,
+    /// Decoded revert reason of a failed transaction, like in Besu
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawReceipt {
+    transaction_hash: Option<H256>,
+    transaction_index: Option<U256>,
+    block_hash: Option<H256>,
+    from: Option<H160>,
+    to: Option<H160>,
+    block_number: Option<U256>,
+    cumulative_gas_used: U256,
+    gas_used: Option<U256>,
+    #[serde(default)]
+    effective_gas_price: Option<U256>,
+    contract_address: Option<H160>,
+    logs: Vec<Log>,
+    root: Option<H256>,
+    logs_bloom: H2048,
+    status: Option<U64>,
+    #[serde(rename = "type", default)]
+    transaction_type: Option<U64>,
+    #[serde(default)]
+    blob_gas_used: Option<U64>,
+    #[serde(default)]
+    blob_gas_price: Option<U256>,
+    #[serde(default)]
+    revert_reason: Option<String>,
+}
+
+impl TryFrom<RawReceipt> for Receipt {
+    type Error = String;
+
+    fn try_from(raw: RawReceipt) -> Result<Receipt, String> {
+        if raw.root.is_some() && raw.status.is_some() {
+            return Err("Invalid receipt: root and status are mutually exclusive".to_string());
+        }
+
+        Ok(Receipt {
+            transaction_hash: raw.transaction_hash,
+            transaction_index: raw.transaction_index,
+            block_hash: raw.block_hash,
+            from: raw.from,
+            to: raw.to,
+            block_number: raw.block_number,
+            cumulative_gas_used: raw.cumulative_gas_used,
+            gas_used: raw.gas_used,
+            effective_gas_price: raw.effective_gas_price,
+            contract_address: raw.contract_address,
+            logs: raw.logs,
+            state_root: raw.root,
+            logs_bloom: raw.logs_bloom,
+            status_code: raw.status,
+            transaction_type: raw.transaction_type,
+            blob_gas_used: raw.blob_gas_used,
+            blob_gas_price: raw.blob_gas_price,
+            revert_reason: raw.revert_reason,
+        })
+    }
+}
+
+impl Receipt {
+    /// Returns whether the transaction succeeded, or `None` for a receipt
+    /// predating Byzantium, which only carries the post-state root.
+    pub fn succeeded(&self) -> Option<bool> {
+        self.status_code.map(|status| !status.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{bloom_contains, build_bloom};
+    use serde_json::{json, Value};
+
+    fn assert_geth_output(receipt: &Receipt, geth: &str) {
+        assert_eq!(
+            serde_json::to_value(receipt).unwrap(),
+            serde_json::from_str::<Value>(geth).unwrap()
+        );
+    }
+
+    #[test]
+    fn blob_receipt_serde() {
+        // Hand-built receipt of the blob transaction of the `Transaction`
+        // tests, in the format of geth. Its hashes are made up, not a capture
+        // of mainnet.
+        let geth = r#"{
+            "blockHash": "0x4c1f0a6e8bb8b3c9d1b8a1e3ff1d3b4f7a62c9a4a2f2c1d5e9b8a7c6d5e4f3a2",
+            "blockNumber": "0x12a05f4",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x5208",
+            "effectiveGasPrice": "0x2e90edd000",
+            "from": "0x6887246668a3b87f54deb3b94ba47a6f63f32985",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0xff00000000000000000000000000000000000010",
+            "transactionHash": "0x6a3f4c9a89d2b6e7f1e5e8f3c6d4e2b7a9c1d3f5e7a9b1c3d5e7f9a1b3c5d7e9",
+            "transactionIndex": "0x0",
+            "type": "0x3",
+            "blobGasUsed": "0x40000",
+            "blobGasPrice": "0x1"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(receipt.blob_gas_used, Some(U64::from(0x40000)));
+        assert_eq!(receipt.blob_gas_price, Some(U256::one()));
+        assert_eq!(receipt.status_code, Some(U64::one()));
+        assert_eq!(receipt.state_root, None);
+
+        assert_eq!(receipt.transaction_type, Some(U64::from(3)));
+        assert_geth_output(&receipt, geth);
+        assert_eq!(
+            serde_json::from_value::<Receipt>(serde_json::to_value(&receipt).unwrap()).unwrap(),
+            receipt
+        );
+    }
+
+    #[test]
+    fn legacy_receipt_omits_blob_fields() {
+        let receipt = Receipt {
+            transaction_hash: Some(H256::repeat_byte(0x11)),
+            transaction_index: Some(U256::zero()),
+            block_hash: Some(H256::repeat_byte(0x22)),
+            from: Some(H160::repeat_byte(0x33)),
+            to: Some(H160::repeat_byte(0x44)),
+            block_number: Some(U256::one()),
+            cumulative_gas_used: U256::from(21_000),
+            gas_used: Some(U256::from(21_000)),
+            effective_gas_price: Some(U256::from(20_000_000_000u64)),
+            contract_address: None,
+            logs: vec![],
+            state_root: None,
+            logs_bloom: H2048::zero(),
+            status_code: Some(U64::one()),
+            transaction_type: Some(U64::zero()),
+            blob_gas_used: None,
+            blob_gas_price: None,
+            revert_reason: None,
+        };
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert!(value.get("blobGasUsed").is_none());
+        assert!(value.get("blobGasPrice").is_none());
+        assert_eq!(serde_json::from_value::<Receipt>(value).unwrap(), receipt);
+    }
+
+    #[test]
+    fn legacy_receipt_geth_output() {
+        // Receipt of the EIP-155 example transaction in the format of geth,
+        // in the made-up block of the `Transaction` tests.
+        let geth = r#"{
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": "0x12a05f2",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x5208",
+            "effectiveGasPrice": "0x4a817c800",
+            "from": "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0x3535353535353535353535353535353535353535",
+            "transactionHash": "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788",
+            "transactionIndex": "0x0",
+            "type": "0x0"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        // The gas price of the transaction.
+        assert_eq!(
+            receipt.effective_gas_price,
+            Some(U256::from(20_000_000_000u64))
+        );
+        assert_eq!(receipt.transaction_type, Some(U64::zero()));
+        assert_geth_output(&receipt, geth);
+    }
+
+    #[test]
+    fn dynamic_fee_receipt_geth_output() {
+        // Receipt of the hand-built dynamic fee transfer of the
+        // `Transaction` tests, in the format of geth.
+        let geth = r#"{
+            "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "blockNumber": "0x12a05f3",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x1d4c0",
+            "effectiveGasPrice": "0x324a9a700",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+            "transactionHash": "0x8f2866a5c1d2f0c7bd2e5ec7a6b5c2f1b8d8c1e2a9a55f2c5e1a2460664abe7d",
+            "transactionIndex": "0x3",
+            "type": "0x2"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(
+            receipt.effective_gas_price,
+            Some(U256::from(13_500_000_000u64))
+        );
+        assert_eq!(receipt.transaction_type, Some(U64::from(2)));
+        assert_geth_output(&receipt, geth);
+    }
+
+    #[test]
+    fn pre_london_receipt_serde() {
+        let s = r#"{"transactionHash":null,"transactionIndex":null,"blockHash":null,"from":null,"to":null,"blockNumber":null,"cumulativeGasUsed":"0x5208","gasUsed":"0x5208","contractAddress":null,"logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1"}"#;
+        let receipt: Receipt = serde_json::from_str(s).unwrap();
+        assert_eq!(receipt.effective_gas_price, None);
+        assert_eq!(receipt.transaction_type, None);
+        assert_eq!(serde_json::to_string(&receipt).unwrap(), s);
+    }
+
+    #[test]
+    fn pre_byzantium_receipt_serde() {
+        // Receipt of a transfer predating Byzantium, in the format of geth.
+        let geth = r#"{
+            "blockHash": "0x4e3a3754410177e6937ef1f84bba68ea139e8d1a2258c5f85db9f1cd715a1bdd",
+            "blockNumber": "0xb443",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x5208",
+            "effectiveGasPrice": "0x2d79883d2000",
+            "from": "0xa1e4380a3b1f749673e270229993ee55f35663b4",
+            "gasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "root": "0x96a8e009d2b88b1483e6941e6812e32263b05683fac202abc622a3e31aed1957",
+            "to": "0x5df9b87991262f6ba471f09758cde1c0fc1de734",
+            "transactionHash": "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060",
+            "transactionIndex": "0x0",
+            "type": "0x0"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(
+            receipt.state_root,
+            Some(
+                "0x96a8e009d2b88b1483e6941e6812e32263b05683fac202abc622a3e31aed1957"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(receipt.status_code, None);
+        assert_eq!(receipt.succeeded(), None);
+        assert_geth_output(&receipt, geth);
+    }
+
+    #[test]
+    fn receipt_status() {
+        let mut receipt: Receipt = serde_json::from_str(
+            r#"{"cumulativeGasUsed":"0x5208","logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","status":"0x1"}"#,
+        )
+        .unwrap();
+        assert_eq!(receipt.succeeded(), Some(true));
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert!(value.get("root").is_none());
+        assert!(value.get("revertReason").is_none());
+        receipt.status_code = Some(U64::zero());
+        assert_eq!(receipt.succeeded(), Some(false));
+    }
+
+    #[test]
+    fn receipt_root_and_status_rejected() {
+        let res = serde_json::from_str::<Receipt>(
+            r#"{"cumulativeGasUsed":"0x5208","logs":[],"logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","root":"0x96a8e009d2b88b1483e6941e6812e32263b05683fac202abc622a3e31aed1957","status":"0x1"}"#,
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Invalid receipt: root and status are mutually exclusive"
+        );
+    }
+
+    #[test]
+    fn creation_receipt_geth_output() {
+        // Hand-built receipt of a contract deployment, in the format of geth.
+        // Its hashes are made up, not a capture of mainnet.
+        let geth = r#"{
+            "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "blockNumber": "0x12a05f3",
+            "contractAddress": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+            "cumulativeGasUsed": "0x2e6b6",
+            "effectiveGasPrice": "0x324a9a700",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gasUsed": "0x2e6b6",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x1",
+            "to": null,
+            "transactionHash": "0x4b1f5d5e3a0f6c2c0d8e9a7b6c5d4e3f2a1b0c9d8e7f6a5b4c3d2e1f0a9b8c7d",
+            "transactionIndex": "0x0",
+            "type": "0x2"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(receipt.to, None);
+        assert_eq!(
+            receipt.contract_address,
+            Some(
+                "0x5fbdb2315678afecb367f032d93f642f64180aa3"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_geth_output(&receipt, geth);
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(value.get("to"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn call_receipt_null_contract_address() {
+        let receipt = Receipt {
+            transaction_hash: Some(H256::repeat_byte(0x11)),
+            transaction_index: Some(U256::zero()),
+            block_hash: Some(H256::repeat_byte(0x22)),
+            from: Some(H160::repeat_byte(0x33)),
+            to: Some(H160::repeat_byte(0x44)),
+            block_number: Some(U256::one()),
+            cumulative_gas_used: U256::from(21_000),
+            gas_used: Some(U256::from(21_000)),
+            effective_gas_price: Some(U256::from(20_000_000_000u64)),
+            contract_address: None,
+            logs: vec![],
+            state_root: None,
+            logs_bloom: H2048::zero(),
+            status_code: Some(U64::one()),
+            transaction_type: Some(U64::zero()),
+            blob_gas_used: None,
+            blob_gas_price: None,
+            revert_reason: None,
+        };
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(value.get("contractAddress"), Some(&Value::Null));
+        assert_eq!(value["from"], "0x3333333333333333333333333333333333333333");
+        assert_eq!(value["to"], "0x4444444444444444444444444444444444444444");
+    }
+
+    #[test]
+    fn failed_receipt_revert_reason() {
+        let geth = r#"{
+            "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "blockNumber": "0x12a05f3",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0x9f0b",
+            "effectiveGasPrice": "0x324a9a700",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gasUsed": "0x9f0b",
+            "logs": [],
+            "logsBloom": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "status": "0x0",
+            "to": "0x5fbdb2315678afecb367f032d93f642f64180aa3",
+            "transactionHash": "0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3",
+            "transactionIndex": "0x0",
+            "type": "0x2"
+        }"#;
+        let mut receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(receipt.succeeded(), Some(false));
+        assert_eq!(receipt.revert_reason, None);
+        assert_geth_output(&receipt, geth);
+
+        receipt.revert_reason = Some("ERC20: transfer amount exceeds balance".to_string());
+        let value = serde_json::to_value(&receipt).unwrap();
+        assert_eq!(
+            value["revertReason"],
+            "ERC20: transfer amount exceeds balance"
+        );
+        assert_eq!(serde_json::from_value::<Receipt>(value).unwrap(), receipt);
+    }
+
+    #[test]
+    fn receipt_logs_bloom() {
+        // Hand-built receipt of an ERC-20 transfer, in the format of geth.
+        let geth = r#"{
+            "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "blockNumber": "0x12a05f3",
+            "contractAddress": null,
+            "cumulativeGasUsed": "0xfde8",
+            "effectiveGasPrice": "0x324a9a700",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gasUsed": "0xfde8",
+            "logs": [
+                {
+                    "address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    "topics": [
+                        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                        "0x000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+                        "0x00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c8"
+                    ],
+                    "data": "0x00000000000000000000000000000000000000000000000000000000000f4240",
+                    "blockNumber": "0x12a05f3",
+                    "transactionHash": "0x3f2a7c1d3d1ff0e0298fd2d9e9b6bc0ee4b2f1d26547ad1d4e1d6788e2bc08b2",
+                    "transactionIndex": "0x0",
+                    "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+                    "logIndex": "0x0",
+                    "removed": false
+                }
+            ],
+            "logsBloom": "0x00000000000000000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000008000000000000000000000000000000000000000000000800000000000000000100000000000000000000000000000010000000000000000000000000000000000000000000000000010000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000002000000200000000000000000000000002000000000000000000000000000000000000000000000000000000001000000000000000000000000000000",
+            "status": "0x1",
+            "to": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            "transactionHash": "0x3f2a7c1d3d1ff0e0298fd2d9e9b6bc0ee4b2f1d26547ad1d4e1d6788e2bc08b2",
+            "transactionIndex": "0x0",
+            "type": "0x2"
+        }"#;
+        let receipt: Receipt = serde_json::from_str(geth).unwrap();
+        assert_eq!(build_bloom(&receipt.logs), receipt.logs_bloom);
+        let log = &receipt.logs[0];
+        assert!(bloom_contains(
+            &receipt.logs_bloom,
+            &log.address,
+            &log.topics
+        ));
+        // Receipts without logs have an empty bloom.
+        let receipt: Receipt = serde_json::from_value(json!({
+            "cumulativeGasUsed": "0x5208",
+            "logs": [],
+            "logsBloom": H2048::zero(),
+            "status": "0x1",
+        }))
+        .unwrap();
+        assert_eq!(build_bloom(&receipt.logs), receipt.logs_bloom);
+    }
 }
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Simulation types (`eth_simulateV1`).
+
+use ethereum_types::{U256, U64};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BlockOverrides, Bytes, CallRequest, CallStateOverride, Log, RichBlock};
+
+/// Calls to simulate within one block.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimBlock {
+    /// Overrides of the block context
+    pub block_overrides: Option<BlockOverrides>,
+    /// Overrides of the state before the calls
+    pub state_overrides: Option<CallStateOverride>,
+    /// Calls, executed in order
+    #[serde(default)]
+    pub calls: Vec<CallRequest>,
+}
+
+/// Request of `eth_simulateV1`.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatePayload {
+    /// Blocks to simulate, in order
+    pub block_state_calls: Vec<SimBlock>,
+    /// Whether ether transfers should be reported as logs
+    #[serde(default)]
+    pub trace_transfers: bool,
+    /// Whether the calls should be validated like transactions
+    #[serde(default)]
+    pub validation: bool,
+    /// Whether the simulated blocks should include full transactions
+    #[serde(default)]
+    pub return_full_transactions: bool,
+}
+
+/// Error of a simulated call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulateError {
+    /// Error code
+    pub code: i64,
+    /// Error message
+    pub message: String,
+    /// Error data, such as the revert data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+}
+
+/// Result of a simulated call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimCallResult {
+    /// Output data
+    pub return_data: Bytes,
+    /// Logs emitted
+    pub logs: Vec<Log>,
+    /// Gas used
+    pub gas_used: U256,
+    /// Status: 1 for success, 0 for failure
+    pub status: U64,
+    /// Error, if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SimulateError>,
+}
+
+/// Simulated block with the results of its calls.
+#[derive(Debug, Serialize)]
+pub struct SimulatedBlock {
+    /// Simulated block
+    #[serde(flatten)]
+    pub block: RichBlock,
+    /// Results of the calls, in order
+    pub calls: Vec<SimCallResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::{H160, H256};
+
+    #[test]
+    fn simulate_payload_deserialize() {
+        // As sent by viem's `simulateBlocks`.
+        let s = r#"{"blockStateCalls":[{"blockOverrides":{"number":"0x1f","time":"0x66b8f3e0","baseFeePerGas":"0x0"},"stateOverrides":{"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266":{"balance":"0xde0b6b3a7640000"}},"calls":[{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x70997970c51812dc3a010c7d01b50e0d17dc79c8","value":"0x1"},{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","data":"0x70a08231000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266"}]},{"calls":[{"from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","to":"0x5fbdb2315678afecb367f032d93f642f64180aa3","data":"0x06fdde03"}]}],"traceTransfers":true,"validation":false,"returnFullTransactions":false}"#;
+        let payload: SimulatePayload = serde_json::from_str(s).unwrap();
+        assert!(payload.trace_transfers);
+        assert_eq!(payload.block_state_calls.len(), 2);
+
+        let first = &payload.block_state_calls[0];
+        let overrides = first.block_overrides.as_ref().unwrap();
+        assert_eq!(overrides.number, Some(U256::from(0x1f)));
+        assert_eq!(overrides.base_fee_per_gas, Some(U256::zero()));
+        let sender: H160 = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            first.state_overrides.as_ref().unwrap()[&sender].balance,
+            Some(U256::from(1_000_000_000_000_000_000u64))
+        );
+        assert_eq!(first.calls.len(), 2);
+        assert_eq!(first.calls[0].value, Some(U256::one()));
+
+        let second = &payload.block_state_calls[1];
+        assert_eq!(second.block_overrides, None);
+        assert_eq!(second.state_overrides, None);
+    }
+
+    #[test]
+    fn sim_call_result_serde() {
+        let s = r#"{"error":{"code":3,"data":"0x08c379a0","message":"execution reverted"},"gasUsed":"0x5a3c","logs":[],"returnData":"0x08c379a0","status":"0x0"}"#;
+        let result: SimCallResult = serde_json::from_str(s).unwrap();
+        assert_eq!(result.status, U64::zero());
+        assert_eq!(result.error.as_ref().unwrap().code, 3);
+        let expected: serde_json::Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), expected);
+
+        let result: SimCallResult = serde_json::from_str(
+            r#"{"gasUsed":"0x5208","logs":[{"address":"0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee","topics":["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"],"data":"0x01","blockHash":null,"blockNumber":null,"transactionHash":null,"transactionIndex":null,"logIndex":null,"transactionLogIndex":null,"removed":false}],"returnData":"0x","status":"0x1"}"#,
+        )
+        .unwrap();
+        assert_eq!(result.error, None);
+        assert_eq!(
+            result.logs[0].topics[0],
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+                .parse::<H256>()
+                .unwrap()
+        );
+    }
+}
@@ -19,7 +19,9 @@
 use std::collections::BTreeMap;
 
 use ethereum_types::{H512, U256};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::Quantity;
 
 /// Sync info
 #[derive(Default, Debug, Serialize, PartialEq)]
@@ -50,11 +52,18 @@ pub struct Peers {
     pub peers: Vec<PeerInfo>,
 }
 
-#[derive(Debug, Serialize)]
-#[serde(untagged)]
-pub enum PeerCount {
-    U32(u32),
-    String(String),
+/// Number of connected peers, serialized as a hex string and deserialized
+/// from a hex string or a JSON number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PeerCount(pub U256);
+
+impl<'a> Deserialize<'a> for PeerCount {
+    fn deserialize<D>(deserializer: D) -> Result<PeerCount, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        Quantity::deserialize(deserializer).map(|count| PeerCount(count.value()))
+    }
 }
 
 /// Peer connection information
@@ -151,3 +160,28 @@ pub struct ChainStatus {
     /// Describes the gap in the blockchain, if there is one: (first, last)
     pub block_gap: Option<(U256, U256)>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peer_count_deserialize() {
+        for (s, count) in [(r#""0x0""#, 0), (r#""0x1a""#, 26), ("0", 0), ("26", 26)] {
+            let deserialized: PeerCount = serde_json::from_str(s).unwrap();
+            assert_eq!(deserialized, PeerCount(U256::from(count)));
+        }
+    }
+
+    #[test]
+    fn peer_count_serialize() {
+        assert_eq!(
+            serde_json::to_string(&PeerCount(U256::zero())).unwrap(),
+            r#""0x0""#
+        );
+        assert_eq!(
+            serde_json::to_string(&PeerCount(U256::from(26))).unwrap(),
+            r#""0x1a""#
+        );
+    }
+}
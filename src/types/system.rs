@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! System types.
+
+use serde::{Deserialize, Serialize};
+
+/// Health of the node (used by `system_health`).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Health {
+    /// Number of connected peers
+    pub peers: u32,
+    /// Whether the node is syncing
+    pub is_syncing: bool,
+    /// Whether the node is expected to be connected to peers, `false` for
+    /// development nodes for instance
+    pub should_have_peers: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_serde() {
+        let s = r#"{"peers":3,"isSyncing":false,"shouldHavePeers":true}"#;
+        let health: Health = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            health,
+            Health {
+                peers: 3,
+                is_syncing: false,
+                should_have_peers: true,
+            }
+        );
+        assert_eq!(serde_json::to_string(&health).unwrap(), s);
+    }
+}
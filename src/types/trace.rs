@@ -0,0 +1,811 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Parity-style trace types.
+
+use std::collections::BTreeMap;
+
+use ethereum_types::{H160, H256, U256};
+use serde::de::{DeserializeOwned, Error};
+use serde::ser::{Error as SerError, SerializeMap, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{from_value, to_value, Value};
+
+use crate::types::{BlockNumber, Bytes};
+
+/// Call type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallType {
+    /// None
+    None,
+    /// Call
+    Call,
+    /// Call code
+    CallCode,
+    /// Delegate call
+    DelegateCall,
+    /// Static call
+    StaticCall,
+}
+
+/// Call action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Call {
+    /// Sender
+    pub from: H160,
+    /// Recipient
+    pub to: H160,
+    /// Transfered value
+    pub value: U256,
+    /// Gas
+    pub gas: U256,
+    /// Input data
+    pub input: Bytes,
+    /// The type of the call
+    pub call_type: CallType,
+}
+
+/// Create action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Create {
+    /// Sender
+    pub from: H160,
+    /// Value
+    pub value: U256,
+    /// Gas
+    pub gas: U256,
+    /// Initialization code
+    pub init: Bytes,
+}
+
+/// Suicide action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Suicide {
+    /// Address
+    pub address: H160,
+    /// Refund address
+    pub refund_address: H160,
+    /// Balance
+    pub balance: U256,
+}
+
+/// Reward type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RewardType {
+    /// Block
+    Block,
+    /// Uncle
+    Uncle,
+    /// EmptyStep (AuthorityRound)
+    EmptyStep,
+    /// External (attributed as part of an external protocol)
+    External,
+}
+
+/// Reward action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Reward {
+    /// Author's address
+    pub author: H160,
+    /// Reward amount
+    pub value: U256,
+    /// Reward type
+    pub reward_type: RewardType,
+}
+
+/// Action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Call
+    Call(Call),
+    /// Create
+    Create(Create),
+    /// Suicide
+    Suicide(Suicide),
+    /// Reward
+    Reward(Reward),
+}
+
+impl Action {
+    fn type_name(&self) -> &'static str {
+        match *self {
+            Action::Call(_) => "call",
+            Action::Create(_) => "create",
+            Action::Suicide(_) => "suicide",
+            Action::Reward(_) => "reward",
+        }
+    }
+}
+
+/// Call result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallResult {
+    /// Gas used
+    pub gas_used: U256,
+    /// Output bytes
+    pub output: Bytes,
+}
+
+/// Create result.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResult {
+    /// Gas used
+    pub gas_used: U256,
+    /// Code
+    pub code: Bytes,
+    /// Assigned address
+    pub address: H160,
+}
+
+/// Response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Res {
+    /// Call
+    Call(CallResult),
+    /// Create
+    Create(CreateResult),
+    /// Call failure
+    FailedCall(String),
+    /// Creation failure
+    FailedCreate(String),
+    /// None
+    None,
+}
+
+/// Trace localized in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedTrace {
+    /// Action
+    pub action: Action,
+    /// Result
+    pub result: Res,
+    /// Trace address
+    pub trace_address: Vec<usize>,
+    /// Subtraces
+    pub subtraces: usize,
+    /// Transaction position
+    pub transaction_position: Option<usize>,
+    /// Transaction hash
+    pub transaction_hash: Option<H256>,
+    /// Block Number
+    pub block_number: u64,
+    /// Block Hash
+    pub block_hash: H256,
+}
+
+fn serialize_action_result<S: SerializeStruct>(
+    struc: &mut S,
+    action: &Action,
+    result: &Res,
+) -> Result<(), S::Error> {
+    match *action {
+        Action::Call(ref call) => struc.serialize_field("action", call)?,
+        Action::Create(ref create) => struc.serialize_field("action", create)?,
+        Action::Suicide(ref suicide) => struc.serialize_field("action", suicide)?,
+        Action::Reward(ref reward) => struc.serialize_field("action", reward)?,
+    }
+    struc.serialize_field("type", action.type_name())?;
+
+    match *result {
+        Res::Call(ref call) => struc.serialize_field("result", call),
+        Res::Create(ref create) => struc.serialize_field("result", create),
+        Res::FailedCall(ref error) | Res::FailedCreate(ref error) => {
+            struc.serialize_field("error", error)
+        }
+        Res::None => struc.serialize_field("result", &Value::Null),
+    }
+}
+
+fn deserialize_action_result<E: Error>(
+    r#type: &str,
+    action: Value,
+    result: Value,
+    error: Option<String>,
+) -> Result<(Action, Res), E> {
+    let invalid = |e: serde_json::Error| E::custom(format!("Invalid trace: {}", e));
+
+    Ok(match r#type {
+        "call" => (
+            Action::Call(from_value(action).map_err(invalid)?),
+            match (error, result) {
+                (Some(error), _) => Res::FailedCall(error),
+                (None, Value::Null) => Res::None,
+                (None, result) => Res::Call(from_value(result).map_err(invalid)?),
+            },
+        ),
+        "create" => (
+            Action::Create(from_value(action).map_err(invalid)?),
+            match (error, result) {
+                (Some(error), _) => Res::FailedCreate(error),
+                (None, Value::Null) => Res::None,
+                (None, result) => Res::Create(from_value(result).map_err(invalid)?),
+            },
+        ),
+        "suicide" => (
+            Action::Suicide(from_value(action).map_err(invalid)?),
+            Res::None,
+        ),
+        "reward" => (
+            Action::Reward(from_value(action).map_err(invalid)?),
+            Res::None,
+        ),
+        other => {
+            return Err(E::custom(format!("Unknown trace type: {}", other)));
+        }
+    })
+}
+
+impl Serialize for LocalizedTrace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut struc = serializer.serialize_struct("LocalizedTrace", 9)?;
+        serialize_action_result(&mut struc, &self.action, &self.result)?;
+        struc.serialize_field("traceAddress", &self.trace_address)?;
+        struc.serialize_field("subtraces", &self.subtraces)?;
+        struc.serialize_field("transactionPosition", &self.transaction_position)?;
+        struc.serialize_field("transactionHash", &self.transaction_hash)?;
+        struc.serialize_field("blockNumber", &self.block_number)?;
+        struc.serialize_field("blockHash", &self.block_hash)?;
+        struc.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawLocalizedTrace {
+    action: Value,
+    r#type: String,
+    #[serde(default)]
+    result: Value,
+    error: Option<String>,
+    trace_address: Vec<usize>,
+    subtraces: usize,
+    transaction_position: Option<usize>,
+    transaction_hash: Option<H256>,
+    block_number: u64,
+    block_hash: H256,
+}
+
+impl<'a> Deserialize<'a> for LocalizedTrace {
+    fn deserialize<D>(deserializer: D) -> Result<LocalizedTrace, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let raw = RawLocalizedTrace::deserialize(deserializer)?;
+        let (action, result) =
+            deserialize_action_result(&raw.r#type, raw.action, raw.result, raw.error)?;
+
+        Ok(LocalizedTrace {
+            action,
+            result,
+            trace_address: raw.trace_address,
+            subtraces: raw.subtraces,
+            transaction_position: raw.transaction_position,
+            transaction_hash: raw.transaction_hash,
+            block_number: raw.block_number,
+            block_hash: raw.block_hash,
+        })
+    }
+}
+
+/// Trace, not localized in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatTrace {
+    /// Action
+    pub action: Action,
+    /// Result
+    pub result: Res,
+    /// Trace address
+    pub trace_address: Vec<usize>,
+    /// Subtraces
+    pub subtraces: usize,
+}
+
+impl Serialize for FlatTrace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut struc = serializer.serialize_struct("FlatTrace", 5)?;
+        serialize_action_result(&mut struc, &self.action, &self.result)?;
+        struc.serialize_field("traceAddress", &self.trace_address)?;
+        struc.serialize_field("subtraces", &self.subtraces)?;
+        struc.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFlatTrace {
+    action: Value,
+    r#type: String,
+    #[serde(default)]
+    result: Value,
+    error: Option<String>,
+    trace_address: Vec<usize>,
+    subtraces: usize,
+}
+
+impl<'a> Deserialize<'a> for FlatTrace {
+    fn deserialize<D>(deserializer: D) -> Result<FlatTrace, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let raw = RawFlatTrace::deserialize(deserializer)?;
+        let (action, result) =
+            deserialize_action_result(&raw.r#type, raw.action, raw.result, raw.error)?;
+
+        Ok(FlatTrace {
+            action,
+            result,
+            trace_address: raw.trace_address,
+            subtraces: raw.subtraces,
+        })
+    }
+}
+
+/// Change of a value between two states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Delta<T> {
+    /// Unchanged, `"="`
+    Unchanged,
+    /// Created, `{"+": value}`
+    Added(T),
+    /// Removed, `{"-": value}`
+    Removed(T),
+    /// Altered, `{"*": {"from": value, "to": value}}`
+    Changed(ChangedType<T>),
+}
+
+/// Altered value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedType<T> {
+    /// Previous value
+    pub from: T,
+    /// Current value
+    pub to: T,
+}
+
+impl<T: Serialize> Serialize for Delta<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (key, value) = match *self {
+            Delta::Unchanged => return serializer.serialize_str("="),
+            Delta::Added(ref value) => ("+", to_value(value)),
+            Delta::Removed(ref value) => ("-", to_value(value)),
+            Delta::Changed(ref changed) => ("*", to_value(changed)),
+        };
+        let value = value.map_err(S::Error::custom)?;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(key, &value)?;
+        map.end()
+    }
+}
+
+impl<'a, T: DeserializeOwned> Deserialize<'a> for Delta<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Delta<T>, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let invalid = |e: serde_json::Error| D::Error::custom(format!("Invalid delta: {}", e));
+
+        match Value::deserialize(deserializer)? {
+            Value::String(ref s) if s == "=" => Ok(Delta::Unchanged),
+            Value::Object(map) if map.len() == 1 => {
+                let (key, value) = map.into_iter().next().expect("map has one entry; qed");
+                match key.as_str() {
+                    "+" => from_value(value).map(Delta::Added).map_err(invalid),
+                    "-" => from_value(value).map(Delta::Removed).map_err(invalid),
+                    "*" => from_value(value).map(Delta::Changed).map_err(invalid),
+                    other => Err(D::Error::custom(format!("Unknown delta type: {}", other))),
+                }
+            }
+            other => Err(D::Error::custom(format!("Invalid delta: {}", other))),
+        }
+    }
+}
+
+/// Changes of an account between two states.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountDiff {
+    /// Balance
+    pub balance: Delta<U256>,
+    /// Nonce
+    pub nonce: Delta<U256>,
+    /// Code
+    pub code: Delta<Bytes>,
+    /// Storage
+    pub storage: BTreeMap<H256, Delta<H256>>,
+}
+
+/// Changes of every account touched by a transaction.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDiff(pub BTreeMap<H160, AccountDiff>);
+
+/// Virtual machine execution trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VMTrace {
+    /// Executed code
+    pub code: Bytes,
+    /// Executed operations
+    pub ops: Vec<VMOperation>,
+}
+
+/// Operation of a virtual machine execution trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VMOperation {
+    /// Program counter
+    pub pc: usize,
+    /// Gas cost
+    pub cost: u64,
+    /// Execution record, `None` if the operation failed
+    pub ex: Option<VMExecutedOperation>,
+    /// Trace of the sub-call or sub-create, if any
+    pub sub: Option<Box<VMTrace>>,
+}
+
+/// Execution record of an operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VMExecutedOperation {
+    /// Remaining gas
+    pub used: u64,
+    /// Pushed stack items
+    pub push: Vec<U256>,
+    /// Memory write, if any
+    pub mem: Option<MemoryDiff>,
+    /// Storage write, if any
+    pub store: Option<StorageDiff>,
+}
+
+/// Memory write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryDiff {
+    /// Offset
+    pub off: usize,
+    /// Written data
+    pub data: Bytes,
+}
+
+/// Storage write.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageDiff {
+    /// Key
+    pub key: U256,
+    /// Value
+    pub val: U256,
+}
+
+/// Trace type requested by `trace_call` and friends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceType {
+    /// Transaction trace
+    Trace,
+    /// Virtual machine execution trace
+    VmTrace,
+    /// State difference
+    StateDiff,
+}
+
+/// Results of a traced call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResults {
+    /// Output of the call
+    pub output: Bytes,
+    /// Call trace, empty if not requested
+    #[serde(default)]
+    pub trace: Vec<FlatTrace>,
+    /// VM trace, if requested
+    #[serde(default)]
+    pub vm_trace: Option<VMTrace>,
+    /// State diff, if requested
+    #[serde(default)]
+    pub state_diff: Option<StateDiff>,
+}
+
+/// Results of a traced transaction, annotated with its hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResultsWithTransactionHash {
+    /// Trace results
+    #[serde(flatten)]
+    pub results: TraceResults,
+    /// Transaction hash
+    #[serde(alias = "txHash")]
+    pub transaction_hash: H256,
+}
+
+/// Trace filter.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFilter {
+    /// From block
+    pub from_block: Option<BlockNumber>,
+    /// To block
+    pub to_block: Option<BlockNumber>,
+    /// From address
+    pub from_address: Option<Vec<H160>>,
+    /// To address
+    pub to_address: Option<Vec<H160>>,
+    /// Output offset
+    pub after: Option<usize>,
+    /// Output amount
+    pub count: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(s: &str) -> LocalizedTrace {
+        let trace: LocalizedTrace = serde_json::from_str(s).unwrap();
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&trace).unwrap(), expected);
+        trace
+    }
+
+    #[test]
+    fn localized_call_trace_serde() {
+        let trace = assert_round_trip(
+            r#"{"action":{"callType":"call","from":"0x83806d539d4ea1c140489a06660319c9a303f874","gas":"0x1a1f8","input":"0x","to":"0x1c39ba39e4735cb65978d4db400ddd70a72dc750","value":"0x7a16c911b4d00000"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":{"gasUsed":"0x2982","output":"0x"},"subtraces":2,"traceAddress":[],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","transactionPosition":2,"type":"call"}"#,
+        );
+        assert!(matches!(trace.action, Action::Call(ref call) if call.call_type == CallType::Call));
+        assert!(matches!(trace.result, Res::Call(_)));
+        assert_eq!(trace.transaction_position, Some(2));
+    }
+
+    #[test]
+    fn localized_block_traces_serde() {
+        let s = r#"[{"action":{"from":"0x5fbdb2315678afecb367f032d93f642f64180aa3","gas":"0x3d090","init":"0x6080604052","value":"0x0"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":{"address":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","code":"0x6080","gasUsed":"0x1f0a2"},"subtraces":0,"traceAddress":[],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","transactionPosition":0,"type":"create"},{"action":{"callType":"call","from":"0x83806d539d4ea1c140489a06660319c9a303f874","gas":"0x0","input":"0x","to":"0x1c39ba39e4735cb65978d4db400ddd70a72dc750","value":"0x7a16c911b4d00000"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":{"gasUsed":"0x0","output":"0x"},"subtraces":0,"traceAddress":[],"transactionHash":"0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50","transactionPosition":1,"type":"call"},{"action":{"author":"0xea674fdde714fd979de3edf0f56aa9716b898ec8","rewardType":"block","value":"0x1bc16d674ec80000"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":null,"subtraces":0,"traceAddress":[],"transactionHash":null,"transactionPosition":null,"type":"reward"}]"#;
+        let traces: Vec<LocalizedTrace> = serde_json::from_str(s).unwrap();
+        assert!(matches!(traces[0].result, Res::Create(_)));
+        assert!(matches!(traces[1].action, Action::Call(_)));
+        assert!(
+            matches!(traces[2].action, Action::Reward(ref reward) if reward.reward_type == RewardType::Block)
+        );
+        assert_eq!(traces[2].transaction_hash, None);
+        assert_eq!(traces[2].transaction_position, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&traces).unwrap(), expected);
+    }
+
+    #[test]
+    fn localized_failed_call_trace_serde() {
+        let trace = assert_round_trip(
+            r#"{"action":{"callType":"delegatecall","from":"0x5fbdb2315678afecb367f032d93f642f64180aa3","gas":"0x2a2b","input":"0x3659cfe6","to":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","value":"0x0"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"error":"Reverted","subtraces":0,"traceAddress":[0,1],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","transactionPosition":4,"type":"call"}"#,
+        );
+        assert_eq!(trace.result, Res::FailedCall("Reverted".into()));
+        assert!(
+            matches!(trace.action, Action::Call(ref call) if call.call_type == CallType::DelegateCall)
+        );
+    }
+
+    #[test]
+    fn localized_failed_create_trace_serde() {
+        let trace = assert_round_trip(
+            r#"{"action":{"from":"0x5fbdb2315678afecb367f032d93f642f64180aa3","gas":"0x0","init":"0x6080","value":"0x0"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"error":"Out of gas","subtraces":0,"traceAddress":[],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","transactionPosition":0,"type":"create"}"#,
+        );
+        assert_eq!(trace.result, Res::FailedCreate("Out of gas".into()));
+    }
+
+    #[test]
+    fn localized_suicide_trace_serde() {
+        let trace = assert_round_trip(
+            r#"{"action":{"address":"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512","balance":"0x7a16c911b4d00000","refundAddress":"0x83806d539d4ea1c140489a06660319c9a303f874"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":null,"subtraces":0,"traceAddress":[0],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","transactionPosition":0,"type":"suicide"}"#,
+        );
+        assert!(matches!(trace.action, Action::Suicide(_)));
+        assert_eq!(trace.result, Res::None);
+    }
+
+    #[test]
+    fn localized_reward_trace_omitted_transaction() {
+        let trace: LocalizedTrace = serde_json::from_str(
+            r#"{"action":{"author":"0xea674fdde714fd979de3edf0f56aa9716b898ec8","rewardType":"uncle","value":"0xde0b6b3a7640000"},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":null,"subtraces":0,"traceAddress":[],"type":"reward"}"#,
+        )
+        .unwrap();
+        assert_eq!(trace.transaction_hash, None);
+        assert_eq!(trace.transaction_position, None);
+    }
+
+    #[test]
+    fn localized_trace_unknown_type() {
+        let res: Result<LocalizedTrace, _> = serde_json::from_str(
+            r#"{"action":{},"blockHash":"0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add","blockNumber":3068185,"result":null,"subtraces":0,"traceAddress":[],"type":"selfdestruct"}"#,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn trace_filter_deserialize() {
+        let s = r#"{"fromBlock":"0x2ed0c4","toBlock":"0x2ed128","fromAddress":["0xd0a6e6c54dbc68db5db3a091b171a77407ff7ccf"],"toAddress":["0x8bbb73bcb5d553b5a556358d27625323fd781d37"],"after":1000,"count":100}"#;
+        let filter: TraceFilter = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            filter,
+            TraceFilter {
+                from_block: Some(BlockNumber::Num(3068100)),
+                to_block: Some(BlockNumber::Num(3068200)),
+                from_address: Some(vec!["0xd0a6e6c54dbc68db5db3a091b171a77407ff7ccf"
+                    .parse()
+                    .unwrap()]),
+                to_address: Some(vec!["0x8bbb73bcb5d553b5a556358d27625323fd781d37"
+                    .parse()
+                    .unwrap()]),
+                after: Some(1000),
+                count: Some(100),
+            }
+        );
+    }
+
+    #[test]
+    fn trace_filter_omitted_fields() {
+        let filter: TraceFilter =
+            serde_json::from_str(r#"{"fromBlock":"earliest","toBlock":"latest"}"#).unwrap();
+        assert_eq!(filter.from_block, Some(BlockNumber::Earliest));
+        assert_eq!(filter.to_block, Some(BlockNumber::Latest));
+        assert_eq!(filter.from_address, None);
+        assert_eq!(filter.to_address, None);
+        assert_eq!(filter.after, None);
+        assert_eq!(filter.count, None);
+
+        let filter: TraceFilter = serde_json::from_str("{}").unwrap();
+        assert_eq!(filter.from_block, None);
+    }
+
+    #[test]
+    fn trace_results_only_trace() {
+        let s = r#"{"output":"0x","stateDiff":null,"trace":[{"action":{"callType":"call","from":"0x83806d539d4ea1c140489a06660319c9a303f874","gas":"0x1a1f8","input":"0x","to":"0x1c39ba39e4735cb65978d4db400ddd70a72dc750","value":"0x7a16c911b4d00000"},"result":{"gasUsed":"0x0","output":"0x"},"subtraces":0,"traceAddress":[],"type":"call"}],"vmTrace":null}"#;
+        let results: TraceResults = serde_json::from_str(s).unwrap();
+        assert_eq!(results.trace.len(), 1);
+        assert_eq!(results.vm_trace, None);
+        assert_eq!(results.state_diff, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&results).unwrap(), expected);
+    }
+
+    #[test]
+    fn trace_type_serde() {
+        let types: Vec<TraceType> =
+            serde_json::from_str(r#"["trace","vmTrace","stateDiff"]"#).unwrap();
+        assert_eq!(
+            types,
+            vec![TraceType::Trace, TraceType::VmTrace, TraceType::StateDiff]
+        );
+        assert!(serde_json::from_str::<TraceType>(r#""vmtrace""#).is_err());
+    }
+
+    #[test]
+    fn trace_results_with_transaction_hash_serde() {
+        let s = r#"{"output":"0x","stateDiff":null,"trace":[],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","vmTrace":null}"#;
+        let results: TraceResultsWithTransactionHash = serde_json::from_str(s).unwrap();
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&results).unwrap(), expected);
+
+        let aliased: TraceResultsWithTransactionHash = serde_json::from_str(
+            r#"{"output":"0x","stateDiff":null,"trace":[],"txHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","vmTrace":null}"#,
+        )
+        .unwrap();
+        assert_eq!(aliased, results);
+    }
+
+    #[test]
+    fn replayed_block_state_diff_serde() {
+        let s = r#"[{"output":"0x","stateDiff":{"0x83806d539d4ea1c140489a06660319c9a303f874":{"balance":{"*":{"from":"0x1bc16d674ec80000","to":"0x1a055690d9db80000"}},"code":"=","nonce":{"*":{"from":"0x0","to":"0x1"}},"storage":{}},"0xe7f1725e7734ce288f8367e1bb143e90bb3f0512":{"balance":{"+":"0x0"},"code":{"+":"0x6080"},"nonce":{"+":"0x1"},"storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":{"+":"0x0000000000000000000000000000000000000000000000000000000000000001"}}}},"trace":[],"transactionHash":"0x17104ac9d3312d8c136b7f44d4b8b47852618065ebfa534bd2d3b5ef218ca1f3","vmTrace":null},{"output":"0x","stateDiff":null,"trace":[],"transactionHash":"0x3dc91b98249fa9f2c5c37486a2427a3a7825be240c1c84961dfb3063d9c04d50","vmTrace":null}]"#;
+        let results: Vec<TraceResultsWithTransactionHash> = serde_json::from_str(s).unwrap();
+        let diff = results[0].results.state_diff.as_ref().unwrap();
+        let sender: H160 = "0x83806d539d4ea1c140489a06660319c9a303f874"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            diff.0[&sender].balance,
+            Delta::Changed(ChangedType {
+                from: U256::from(2_000_000_000_000_000_000u64),
+                to: U256::from(30_000_000_000_000_000_000u128),
+            })
+        );
+        assert_eq!(diff.0[&sender].code, Delta::Unchanged);
+        assert_eq!(results[1].results.state_diff, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&results).unwrap(), expected);
+
+        let empty: Vec<TraceResultsWithTransactionHash> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn delta_serde() {
+        let cases = [
+            (Delta::Unchanged, r#""=""#),
+            (Delta::Added(U256::from(1)), r#"{"+":"0x1"}"#),
+            (Delta::Removed(U256::from(2)), r#"{"-":"0x2"}"#),
+            (
+                Delta::Changed(ChangedType {
+                    from: U256::from(3),
+                    to: U256::from(4),
+                }),
+                r#"{"*":{"from":"0x3","to":"0x4"}}"#,
+            ),
+        ];
+        for (delta, s) in cases {
+            assert_eq!(serde_json::to_string(&delta).unwrap(), s);
+            assert_eq!(serde_json::from_str::<Delta<U256>>(s).unwrap(), delta);
+        }
+    }
+
+    #[test]
+    fn delta_deserialize_invalid() {
+        for s in [
+            r#""""#,
+            r#""+""#,
+            r#"{}"#,
+            r#"{"=":"0x1"}"#,
+            r#"{"+":"0x1","-":"0x1"}"#,
+            r#"{"*":{"from":"0x1"}}"#,
+            r#"null"#,
+        ] {
+            assert!(serde_json::from_str::<Delta<U256>>(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn account_diff_storage_serde() {
+        let s = r#"{"balance":"=","nonce":"=","code":"=","storage":{"0x0000000000000000000000000000000000000000000000000000000000000001":{"*":{"from":"0x0000000000000000000000000000000000000000000000000000000000000002","to":"0x0000000000000000000000000000000000000000000000000000000000000003"}},"0x0000000000000000000000000000000000000000000000000000000000000004":{"-":"0x0000000000000000000000000000000000000000000000000000000000000005"}}}"#;
+        let diff: AccountDiff = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            diff.storage[&H256::from_low_u64_be(4)],
+            Delta::Removed(H256::from_low_u64_be(5))
+        );
+        assert_eq!(serde_json::to_string(&diff).unwrap(), s);
+    }
+
+    #[test]
+    fn vm_trace_nested_call_serde() {
+        let s = r#"{"code":"0x6000600060006000600073e7f1725e7734ce288f8367e1bb143e90bb3f05125af1","ops":[{"cost":3,"ex":{"mem":null,"push":["0x0"],"store":null,"used":99997},"pc":0,"sub":null},{"cost":100,"ex":{"mem":null,"push":["0x1"],"store":null,"used":97000},"pc":22,"sub":{"code":"0x602a60005260206000f3","ops":[{"cost":3,"ex":{"mem":null,"push":["0x2a"],"store":null,"used":2897},"pc":0,"sub":null},{"cost":6,"ex":{"mem":{"data":"0x000000000000000000000000000000000000000000000000000000000000002a","off":0},"push":[],"store":null,"used":2888},"pc":4,"sub":null},{"cost":20000,"ex":{"mem":null,"push":[],"store":{"key":"0x0","val":"0x2a"},"used":868},"pc":7,"sub":null}]}},{"cost":0,"ex":null,"pc":23,"sub":null}]}"#;
+        let trace: VMTrace = serde_json::from_str(s).unwrap();
+        let sub = trace.ops[1].sub.as_ref().unwrap();
+        assert_eq!(
+            sub.ops[1].ex.as_ref().unwrap().mem,
+            Some(MemoryDiff {
+                off: 0,
+                data: Bytes::new(H256::from_low_u64_be(0x2a).as_bytes().to_vec()),
+            })
+        );
+        assert_eq!(
+            sub.ops[2].ex.as_ref().unwrap().store,
+            Some(StorageDiff {
+                key: U256::zero(),
+                val: U256::from(0x2a),
+            })
+        );
+        assert_eq!(trace.ops[2].ex, None);
+        let expected: Value = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_value(&trace).unwrap(), expected);
+    }
+}
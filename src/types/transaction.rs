@@ -16,28 +16,29 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::Bytes;
+use crate::types::{AccessList, Bytes};
 use ethereum_types::{H160, H256, H512, U256, U64};
 use serde::ser::SerializeStruct;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex},
 };
 
 /// Transaction
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawTransaction")]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
     /// Hash
     pub hash: H256,
     /// Nonce
     pub nonce: U256,
-    /// Block hash
+    /// Block hash, serialized as `null` when pending
     pub block_hash: Option<H256>,
-    /// Block number
+    /// Block number, serialized as `null` when pending
     pub block_number: Option<U256>,
-    /// Transaction Index
+    /// Transaction Index, serialized as `null` when pending
     pub transaction_index: Option<U256>,
     /// Sender
     pub from: H160,
@@ -45,20 +46,39 @@ pub struct Transaction {
     pub to: Option<H160>,
     /// Transfered value
     pub value: U256,
-    /// Gas Price
+    /// Gas Price, the effective price paid for an included dynamic fee
+    /// transaction
     pub gas_price: U256,
+    /// Maximum fee per gas of a dynamic fee transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<U256>,
+    /// Maximum priority fee per gas of a dynamic fee transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<U256>,
     /// Gas
     pub gas: U256,
     /// Data
     pub input: Bytes,
-    /// Creates contract
+    /// Address of the created contract for a contract creation
     pub creates: Option<H160>,
-    /// Raw transaction data
+    /// Signed, RLP-encoded transaction
     pub raw: Bytes,
     /// Public key of the signer.
     pub public_key: Option<H512>,
     /// The network id of the transaction, if any.
     pub chain_id: Option<U64>,
+    /// EIP-2718 type of the transaction, if any.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<U64>,
+    /// EIP-2930 access list of a typed transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<AccessList>,
+    /// Maximum fee per blob gas of a blob transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_blob_gas: Option<U256>,
+    /// Versioned hashes of the blobs of a blob transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_versioned_hashes: Option<Vec<H256>>,
     /// The standardised V field of the signature (0 or 1).
     pub standard_v: U256,
     /// The standardised V field of the signature.
@@ -67,6 +87,155 @@ pub struct Transaction {
     pub r: U256,
     /// The S field of the signature.
     pub s: U256,
+    /// The parity of the y coordinate of the signature of a typed
+    /// transaction, equal to `v`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y_parity: Option<U64>,
+    /// Condition of a scheduled transaction, as returned by OpenEthereum.
+    #[cfg(feature = "parity-compat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<TransactionCondition>,
+}
+
+/// Condition a scheduled transaction waits for before being propagated, as
+/// returned by OpenEthereum.
+#[cfg(feature = "parity-compat")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionCondition {
+    /// Valid at the given block number
+    #[serde(rename = "block")]
+    Number(u64),
+    /// Valid at the given unix timestamp
+    #[serde(rename = "time")]
+    Timestamp(u64),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTransaction {
+    hash: H256,
+    nonce: U256,
+    block_hash: Option<H256>,
+    block_number: Option<U256>,
+    transaction_index: Option<U256>,
+    from: H160,
+    to: Option<H160>,
+    value: U256,
+    gas_price: U256,
+    #[serde(default)]
+    max_fee_per_gas: Option<U256>,
+    #[serde(default)]
+    max_priority_fee_per_gas: Option<U256>,
+    gas: U256,
+    input: Bytes,
+    creates: Option<H160>,
+    #[serde(default)]
+    raw: Bytes,
+    public_key: Option<H512>,
+    chain_id: Option<U64>,
+    #[serde(rename = "type", default)]
+    transaction_type: Option<U64>,
+    access_list: Option<AccessList>,
+    max_fee_per_blob_gas: Option<U256>,
+    blob_versioned_hashes: Option<Vec<H256>>,
+    #[serde(default)]
+    standard_v: U256,
+    v: Option<U256>,
+    r: U256,
+    s: U256,
+    y_parity: Option<U64>,
+    #[cfg(feature = "parity-compat")]
+    condition: Option<TransactionCondition>,
+}
+
+impl TryFrom<RawTransaction> for Transaction {
+    type Error = String;
+
+    fn try_from(raw: RawTransaction) -> Result<Transaction, String> {
+        let typed = raw
+            .transaction_type
+            .is_some_and(|transaction_type| !transaction_type.is_zero());
+        let (v, y_parity) = match (raw.v, raw.y_parity) {
+            (Some(v), Some(y_parity)) => {
+                if signature_parity(v) != Some(y_parity) {
+                    return Err(format!(
+                        "Invalid signature: v {} does not match yParity {}",
+                        v, y_parity
+                    ));
+                }
+                (v, Some(y_parity))
+            }
+            (Some(v), None) if typed => match signature_parity(v) {
+                Some(y_parity) if v <= U256::one() => (v, Some(y_parity)),
+                _ => return Err(format!("Invalid signature: v {} of a typed transaction", v)),
+            },
+            (Some(v), None) => (v, None),
+            (None, Some(y_parity)) => (U256::from(y_parity.as_u64()), Some(y_parity)),
+            (None, None) => return Err("missing field `v`".to_string()),
+        };
+
+        Ok(Transaction {
+            hash: raw.hash,
+            nonce: raw.nonce,
+            block_hash: raw.block_hash,
+            block_number: raw.block_number,
+            transaction_index: raw.transaction_index,
+            from: raw.from,
+            to: raw.to,
+            value: raw.value,
+            gas_price: raw.gas_price,
+            max_fee_per_gas: raw.max_fee_per_gas,
+            max_priority_fee_per_gas: raw.max_priority_fee_per_gas,
+            gas: raw.gas,
+            input: raw.input,
+            creates: raw.creates,
+            raw: raw.raw,
+            public_key: raw.public_key,
+            chain_id: raw.chain_id,
+            transaction_type: raw.transaction_type,
+            access_list: raw.access_list,
+            max_fee_per_blob_gas: raw.max_fee_per_blob_gas,
+            blob_versioned_hashes: raw.blob_versioned_hashes,
+            standard_v: raw.standard_v,
+            v,
+            r: raw.r,
+            s: raw.s,
+            y_parity,
+            #[cfg(feature = "parity-compat")]
+            condition: raw.condition,
+        })
+    }
+}
+
+impl Transaction {
+    /// Returns the transaction as a pending transaction of the pool, as
+    /// returned by `eth_pendingTransactions`, the full transactions stream
+    /// of `newPendingTransactions` and `eth_getTransactionByHash`: without
+    /// block hash, number and index, and with the maximum fee per gas as
+    /// gas price for a dynamic fee transaction.
+    pub fn into_pending(self) -> Transaction {
+        Transaction {
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+            gas_price: self.max_fee_per_gas.unwrap_or(self.gas_price),
+            ..self
+        }
+    }
+}
+
+/// Returns the y parity encoded in `v`: `v` itself for a typed transaction,
+/// or the parity of a legacy `27`/`28` or EIP-155 `v`.
+fn signature_parity(v: U256) -> Option<U64> {
+    if v > U256::from(u64::MAX) {
+        return None;
+    }
+    match v.as_u64() {
+        v @ (0 | 1) => Some(U64::from(v)),
+        v @ (27 | 28) => Some(U64::from(v - 27)),
+        v if v >= 35 => Some(U64::from((v - 35) & 1)),
+        _ => None,
+    }
 }
 
 /// Local Transaction Status
@@ -151,7 +320,7 @@ impl Serialize for LocalTransactionStatus {
 }
 
 /// Geth-compatible output for eth_signTransaction method
-#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RichRawTransaction {
     /// Raw transaction RLP
     pub raw: Bytes,
@@ -175,3 +344,428 @@ impl PendingTransaction {
 }
 
 pub type PendingTransactions = Option<Arc<Mutex<HashMap<H256, PendingTransaction>>>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AccessListItem;
+    use serde_json::Value;
+
+    #[test]
+    fn pending_transaction_serialize() {
+        let tx = Transaction {
+            hash: H256::repeat_byte(0x11),
+            from: H160::repeat_byte(0x22),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&tx).unwrap();
+        for field in ["blockHash", "blockNumber", "transactionIndex"] {
+            assert_eq!(value.get(field), Some(&Value::Null), "{}", field);
+        }
+    }
+
+    #[test]
+    fn signed_legacy_transaction_serialize() {
+        let raw = Bytes::new(vec![0xf8, 0x6b, 0x80]);
+        let result = RichRawTransaction {
+            raw: raw.clone(),
+            transaction: Transaction {
+                nonce: U256::zero(),
+                gas_price: U256::from(20_000_000_000u64),
+                gas: U256::from(21_000),
+                to: Some(H160::repeat_byte(0x35)),
+                value: U256::from(1_000_000_000_000_000_000u64),
+                raw,
+                v: U256::from(37),
+                ..Default::default()
+            },
+        };
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(value["raw"], "0xf86b80");
+        assert_eq!(value["tx"]["blockHash"], Value::Null);
+        assert_eq!(value["tx"]["gas"], "0x5208");
+        assert_eq!(value["tx"]["v"], "0x25");
+        assert_eq!(
+            serde_json::from_value::<RichRawTransaction>(value).unwrap(),
+            result
+        );
+    }
+
+    #[test]
+    fn signed_dynamic_fee_transaction_serialize() {
+        let raw = Bytes::new(vec![0x02, 0xf8, 0x72, 0x01]);
+        let result = RichRawTransaction {
+            raw: raw.clone(),
+            transaction: Transaction {
+                chain_id: Some(U64::from(1)),
+                gas: U256::from(21_000),
+                to: Some(H160::repeat_byte(0x35)),
+                raw,
+                v: U256::from(1),
+                ..Default::default()
+            },
+        };
+        let value = serde_json::to_value(&result).unwrap();
+        assert_eq!(value["raw"], "0x02f87201");
+        assert_eq!(value["tx"]["chainId"], "0x1");
+        assert_eq!(value["tx"]["blockNumber"], Value::Null);
+        assert_eq!(value["tx"]["transactionIndex"], Value::Null);
+        assert_eq!(
+            serde_json::from_value::<RichRawTransaction>(value).unwrap(),
+            result
+        );
+    }
+
+    // Fields always serialized by `Transaction` that geth doesn't return.
+    const PARITY_FIELDS: [&str; 4] = ["creates", "publicKey", "raw", "standardV"];
+
+    // Checks that the transaction serializes to the geth response, but for
+    // the parity fields, and that the response deserializes to it.
+    fn assert_geth_output(tx: &Transaction, geth: &str) {
+        let geth: Value = serde_json::from_str(geth).unwrap();
+        assert_eq!(
+            &serde_json::from_value::<Transaction>(geth.clone()).unwrap(),
+            tx
+        );
+        let mut value = serde_json::to_value(tx).unwrap();
+        let object = value.as_object_mut().unwrap();
+        for key in PARITY_FIELDS {
+            assert!(geth.get(key).is_none(), "{}", key);
+            assert!(object.remove(key).is_some(), "{}", key);
+        }
+        assert_eq!(value, geth);
+    }
+
+    #[test]
+    fn legacy_transaction_geth_fields() {
+        // The EIP-155 example transaction in the format of geth, placed in a
+        // made-up block since it was never mined.
+        let geth = r#"{
+            "blockHash": "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add",
+            "blockNumber": "0x12a05f2",
+            "from": "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f",
+            "gas": "0x5208",
+            "gasPrice": "0x4a817c800",
+            "hash": "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788",
+            "input": "0x",
+            "nonce": "0x9",
+            "to": "0x3535353535353535353535353535353535353535",
+            "transactionIndex": "0x0",
+            "value": "0xde0b6b3a7640000",
+            "type": "0x0",
+            "chainId": "0x1",
+            "v": "0x25",
+            "r": "0x28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276",
+            "s": "0x67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        }"#;
+        let tx = Transaction {
+            hash: "0x33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788"
+                .parse()
+                .unwrap(),
+            nonce: U256::from(9),
+            block_hash: Some(
+                "0x7eb25504e4c202cf3d62fd585d3e238f592c780cca82dacb2ed3cb5b38883add"
+                    .parse()
+                    .unwrap(),
+            ),
+            block_number: Some(U256::from(19_531_250)),
+            transaction_index: Some(U256::zero()),
+            from: "0x9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f"
+                .parse()
+                .unwrap(),
+            to: Some(H160::repeat_byte(0x35)),
+            value: U256::from(1_000_000_000_000_000_000u64),
+            gas_price: U256::from(20_000_000_000u64),
+            gas: U256::from(21_000),
+            chain_id: Some(U64::from(1)),
+            transaction_type: Some(U64::zero()),
+            v: U256::from(37),
+            r: U256::from_str_radix(
+                "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276",
+                16,
+            )
+            .unwrap(),
+            s: U256::from_str_radix(
+                "67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83",
+                16,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        assert_geth_output(&tx, geth);
+        let value = serde_json::to_value(&tx).unwrap();
+        assert!(value.get("maxFeePerGas").is_none());
+        assert!(value.get("maxPriorityFeePerGas").is_none());
+        assert!(value.get("yParity").is_none());
+        assert!(value.get("accessList").is_none());
+        assert!(value.get("maxFeePerBlobGas").is_none());
+        assert!(value.get("blobVersionedHashes").is_none());
+    }
+
+    #[test]
+    fn dynamic_fee_transaction_geth_fields() {
+        // A hand-built transfer paying a 1.5 gwei tip on top of a 12 gwei
+        // base fee, in the format of geth. Its hashes and signature are
+        // made up, not a capture of mainnet.
+        let geth = r#"{
+            "blockHash": "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b",
+            "blockNumber": "0x12a05f3",
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gas": "0x5208",
+            "gasPrice": "0x324a9a700",
+            "maxFeePerGas": "0x6fc23ac00",
+            "maxPriorityFeePerGas": "0x59682f00",
+            "hash": "0x8f2866a5c1d2f0c7bd2e5ec7a6b5c2f1b8d8c1e2a9a55f2c5e1a2460664abe7d",
+            "input": "0x",
+            "nonce": "0x2a",
+            "to": "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+            "transactionIndex": "0x3",
+            "value": "0x2386f26fc10000",
+            "type": "0x2",
+            "accessList": [],
+            "chainId": "0x1",
+            "v": "0x1",
+            "r": "0x5f9b3e5b8a1c9e2e8d6f0b7c4a3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d",
+            "s": "0x1a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809",
+            "yParity": "0x1"
+        }"#;
+        let tx: Transaction = serde_json::from_str(geth).unwrap();
+        assert_eq!(tx.transaction_type, Some(U64::from(2)));
+        assert_eq!(tx.max_fee_per_gas, Some(U256::from(30_000_000_000u64)));
+        assert_eq!(
+            tx.max_priority_fee_per_gas,
+            Some(U256::from(1_500_000_000u64))
+        );
+        assert_eq!(tx.gas_price, U256::from(13_500_000_000u64));
+        assert_eq!(tx.v, U256::one());
+        assert_eq!(tx.y_parity, Some(U64::one()));
+        assert_eq!(tx.access_list, Some(vec![]));
+        assert_geth_output(&tx, geth);
+    }
+
+    #[test]
+    fn access_list_transaction_geth_fields() {
+        // A hand-built type-1 call accessing two slots of a token contract,
+        // in the format of geth. Its hashes and signature are made up.
+        let access_list = r#"[{"address":"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000003","0x10d6a54a4754c8869d6886b5f5d7fbfa5b4522237ea5c60d11bc4e7a1ff9390b"]},{"address":"0x43506849d7c04f9138d1a2050bbf3a0c054402dd","storageKeys":[]}]"#;
+        let geth = format!(
+            r#"{{"blockHash":"0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b","blockNumber":"0x12a05f3","from":"0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266","gas":"0x11170","gasPrice":"0x2540be400","hash":"0x3f2a7c1d3d1ff0e0298fd2d9e9b6bc0ee4b2f1d26547ad1d4e1d6788e2bc08b2","input":"0xa9059cbb00000000000000000000000070997970c51812dc3a010c7d01b50e0d17dc79c800000000000000000000000000000000000000000000000000000000000f4240","nonce":"0x2b","to":"0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48","transactionIndex":"0x4","value":"0x0","type":"0x1","accessList":{},"chainId":"0x1","v":"0x0","r":"0x2c4d3f6a1b0e9d8c7b6a5f4e3d2c1b0a9f8e7d6c5b4a3f2e1d0c9b8a7f6e5d4c","s":"0x6b5a4f3e2d1c0b9a8f7e6d5c4b3a2f1e0d9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a","yParity":"0x0"}}"#,
+            access_list
+        );
+        let tx: Transaction = serde_json::from_str(&geth).unwrap();
+        assert_eq!(
+            tx.access_list,
+            Some(vec![
+                AccessListItem {
+                    address: "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+                        .parse()
+                        .unwrap(),
+                    storage_keys: vec![
+                        H256::from_low_u64_be(3),
+                        "0x10d6a54a4754c8869d6886b5f5d7fbfa5b4522237ea5c60d11bc4e7a1ff9390b"
+                            .parse()
+                            .unwrap(),
+                    ],
+                },
+                AccessListItem {
+                    address: "0x43506849d7c04f9138d1a2050bbf3a0c054402dd"
+                        .parse()
+                        .unwrap(),
+                    storage_keys: vec![],
+                },
+            ])
+        );
+        assert_eq!(serde_json::to_string(&tx.access_list).unwrap(), access_list);
+        assert_geth_output(&tx, &geth);
+    }
+
+    #[test]
+    fn typed_transaction_y_parity_serialize() {
+        let tx = Transaction {
+            hash: H256::repeat_byte(0x11),
+            nonce: U256::from(42),
+            from: H160::repeat_byte(0x22),
+            to: Some(H160::repeat_byte(0x33)),
+            gas: U256::from(21_000),
+            gas_price: U256::from(13_500_000_000u64),
+            max_fee_per_gas: Some(U256::from(30_000_000_000u64)),
+            max_priority_fee_per_gas: Some(U256::from(1_500_000_000u64)),
+            chain_id: Some(U64::one()),
+            transaction_type: Some(U64::from(2)),
+            v: U256::one(),
+            r: U256::from(2),
+            s: U256::from(3),
+            y_parity: Some(U64::one()),
+            ..Default::default()
+        };
+        let serialized = serde_json::to_string(&tx).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"hash":"0x1111111111111111111111111111111111111111111111111111111111111111","nonce":"0x2a","blockHash":null,"blockNumber":null,"transactionIndex":null,"from":"0x2222222222222222222222222222222222222222","to":"0x3333333333333333333333333333333333333333","value":"0x0","gasPrice":"0x324a9a700","maxFeePerGas":"0x6fc23ac00","maxPriorityFeePerGas":"0x59682f00","gas":"0x5208","input":"0x","creates":null,"raw":"0x","publicKey":null,"chainId":"0x1","type":"0x2","standardV":"0x0","v":"0x1","r":"0x2","s":"0x3","yParity":"0x1"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Transaction>(&serialized).unwrap(),
+            tx
+        );
+    }
+
+    #[test]
+    fn y_parity_deserialize() {
+        let fields = r#""hash":"0x1111111111111111111111111111111111111111111111111111111111111111","nonce":"0x0","blockHash":null,"blockNumber":null,"transactionIndex":null,"from":"0x2222222222222222222222222222222222222222","to":null,"value":"0x0","gasPrice":"0x0","gas":"0x0","input":"0x","r":"0x2","s":"0x3""#;
+        let parse =
+            |rest: &str| serde_json::from_str::<Transaction>(&format!("{{{},{}}}", fields, rest));
+
+        // Only `yParity`, as returned by newer clients.
+        let tx = parse(r#""type":"0x2","yParity":"0x1""#).unwrap();
+        assert_eq!((tx.v, tx.y_parity), (U256::one(), Some(U64::one())));
+        // Only `v` of a typed transaction.
+        let tx = parse(r#""type":"0x1","v":"0x0""#).unwrap();
+        assert_eq!((tx.v, tx.y_parity), (U256::zero(), Some(U64::zero())));
+        // Only `v` of a legacy transaction.
+        let tx = parse(r#""v":"0x25""#).unwrap();
+        assert_eq!((tx.v, tx.y_parity), (U256::from(37), None));
+        // Both, including for a legacy transaction.
+        let tx = parse(r#""v":"0x26","yParity":"0x1""#).unwrap();
+        assert_eq!((tx.v, tx.y_parity), (U256::from(38), Some(U64::one())));
+
+        assert!(parse(r#""type":"0x2","v":"0x1","yParity":"0x0""#).is_err());
+        assert!(parse(r#""type":"0x2","v":"0x25""#).is_err());
+        assert!(parse(r#""type":"0x2""#).is_err());
+    }
+
+    #[test]
+    fn blob_transaction_geth_fields() {
+        // A hand-built blob transaction of a rollup batcher, in the format of
+        // geth. Its hashes and signature are made up, not a capture of
+        // mainnet.
+        let geth = r#"{
+            "blockHash": "0x4c1f0a6e8bb8b3c9d1b8a1e3ff1d3b4f7a62c9a4a2f2c1d5e9b8a7c6d5e4f3a2",
+            "blockNumber": "0x12a05f4",
+            "from": "0x6887246668a3b87f54deb3b94ba47a6f63f32985",
+            "gas": "0x5208",
+            "gasPrice": "0x2e90edd000",
+            "maxFeePerGas": "0x3b9aca000",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "maxFeePerBlobGas": "0x3b9aca00",
+            "hash": "0x6a3f4c9a89d2b6e7f1e5e8f3c6d4e2b7a9c1d3f5e7a9b1c3d5e7f9a1b3c5d7e9",
+            "input": "0x",
+            "nonce": "0x1d1e",
+            "to": "0xff00000000000000000000000000000000000010",
+            "transactionIndex": "0x0",
+            "value": "0x0",
+            "type": "0x3",
+            "accessList": [],
+            "chainId": "0x1",
+            "blobVersionedHashes": [
+                "0x01a915e4d2d7d5f62d0aa4b4c2e4f2b3d2a7d1e2b3c4d5e6f708192a3b4c5d6e",
+                "0x01b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90"
+            ],
+            "v": "0x0",
+            "r": "0x3e5f7a9b1c3d5e7f9a1b3c5d7e9f1a3b5c7d9e1f3a5b7c9d1e3f5a7b9c1d3e5f",
+            "s": "0x4f6a8b0c2d4e6f8a0b2c4d6e8f0a2b4c6d8e0f2a4b6c8d0e2f4a6b8c0d2e4f6a",
+            "yParity": "0x0"
+        }"#;
+        let tx: Transaction = serde_json::from_str(geth).unwrap();
+        assert_eq!(tx.transaction_type, Some(U64::from(3)));
+        assert_eq!(tx.max_fee_per_blob_gas, Some(U256::from(1_000_000_000)));
+        assert_eq!(tx.blob_versioned_hashes.as_ref().map(Vec::len), Some(2));
+        assert_eq!(tx.y_parity, Some(U64::zero()));
+        assert_geth_output(&tx, geth);
+        let serialized = serde_json::to_string(&tx).unwrap();
+        assert!(serialized.contains(r#""type":"0x3""#));
+        assert_eq!(
+            serde_json::from_str::<Transaction>(&serialized).unwrap(),
+            tx
+        );
+    }
+
+    #[test]
+    fn pending_transaction_geth_fields() {
+        // The hand-built dynamic fee transfer above while pending, in the
+        // format of geth.
+        let geth = r#"{
+            "blockHash": null,
+            "blockNumber": null,
+            "from": "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266",
+            "gas": "0x5208",
+            "gasPrice": "0x6fc23ac00",
+            "maxFeePerGas": "0x6fc23ac00",
+            "maxPriorityFeePerGas": "0x59682f00",
+            "hash": "0x8f2866a5c1d2f0c7bd2e5ec7a6b5c2f1b8d8c1e2a9a55f2c5e1a2460664abe7d",
+            "input": "0x",
+            "nonce": "0x2a",
+            "to": "0x70997970c51812dc3a010c7d01b50e0d17dc79c8",
+            "transactionIndex": null,
+            "value": "0x2386f26fc10000",
+            "type": "0x2",
+            "accessList": [],
+            "chainId": "0x1",
+            "v": "0x1",
+            "r": "0x5f9b3e5b8a1c9e2e8d6f0b7c4a3d2e1f0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d",
+            "s": "0x1a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809",
+            "yParity": "0x1"
+        }"#;
+        let included = Transaction {
+            block_hash: Some(
+                "0x88df016429689c079f3b2f6ad39fa052532c56795b733da78a91ebe6a713944b"
+                    .parse()
+                    .unwrap(),
+            ),
+            block_number: Some(U256::from(19_531_251)),
+            transaction_index: Some(U256::from(3)),
+            gas_price: U256::from(13_500_000_000u64),
+            ..serde_json::from_str(geth).unwrap()
+        };
+        let tx = included.into_pending();
+        assert_geth_output(&tx, geth);
+        assert_eq!(tx.gas_price, U256::from(30_000_000_000u64));
+
+        let legacy = Transaction {
+            block_number: Some(U256::one()),
+            gas_price: U256::from(20_000_000_000u64),
+            ..Default::default()
+        }
+        .into_pending();
+        assert_eq!(legacy.block_number, None);
+        assert_eq!(legacy.gas_price, U256::from(20_000_000_000u64));
+    }
+
+    #[cfg(feature = "parity-compat")]
+    #[test]
+    fn transaction_condition_serde() {
+        let tx = Transaction {
+            condition: Some(TransactionCondition::Number(0x10)),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&tx).unwrap();
+        assert_eq!(value["condition"], serde_json::json!({"block": 16}));
+        assert_eq!(serde_json::from_value::<Transaction>(value).unwrap(), tx);
+
+        let tx = Transaction {
+            condition: Some(TransactionCondition::Timestamp(1_722_000_000)),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&tx).unwrap();
+        assert_eq!(value["condition"], serde_json::json!({"time": 1722000000}));
+        assert_eq!(serde_json::from_value::<Transaction>(value).unwrap(), tx);
+    }
+
+    #[test]
+    fn parity_fields_serialize() {
+        // The output must not depend on `parity-compat` when there is no
+        // condition.
+        let tx = Transaction {
+            hash: H256::repeat_byte(0x11),
+            from: H160::repeat_byte(0x22),
+            creates: Some(H160::repeat_byte(0x33)),
+            raw: Bytes::new(vec![0xf8, 0x6b, 0x80]),
+            standard_v: U256::one(),
+            v: U256::from(38),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&tx).unwrap(),
+            r#"{"hash":"0x1111111111111111111111111111111111111111111111111111111111111111","nonce":"0x0","blockHash":null,"blockNumber":null,"transactionIndex":null,"from":"0x2222222222222222222222222222222222222222","to":null,"value":"0x0","gasPrice":"0x0","gas":"0x0","input":"0x","creates":"0x3333333333333333333333333333333333333333","raw":"0xf86b80","publicKey":null,"chainId":null,"standardV":"0x1","v":"0x26","r":"0x0","s":"0x0"}"#
+        );
+    }
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversion of `TransactionRequest` into a message to sign.
+
+use ethereum::{
+    AccessListItem as EthereumAccessListItem, EIP1559TransactionMessage, EIP2930TransactionMessage,
+    LegacyTransactionMessage, TransactionAction,
+};
+use ethereum_types::{H256, U256, U64};
+
+use crate::types::{RequestValidationError, TransactionKind, TransactionRequest};
+
+/// Unsigned transaction, of any kind supported by the `ethereum` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionMessage {
+    /// Legacy transaction, signed as EIP-155.
+    Legacy(LegacyTransactionMessage),
+    /// EIP-2930 transaction
+    EIP2930(EIP2930TransactionMessage),
+    /// EIP-1559 transaction
+    EIP1559(EIP1559TransactionMessage),
+}
+
+impl TransactionMessage {
+    /// Returns the hash to sign.
+    pub fn hash(&self) -> H256 {
+        match self {
+            TransactionMessage::Legacy(message) => message.hash(),
+            TransactionMessage::EIP2930(message) => message.hash(),
+            TransactionMessage::EIP1559(message) => message.hash(),
+        }
+    }
+}
+
+/// Values for the fields a request may leave out.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FillDefaults {
+    /// Nonce, usually the pending nonce of the sender
+    pub nonce: U256,
+    /// Gas limit
+    pub gas_limit: U256,
+    /// Gas price of legacy and EIP-2930 transactions
+    pub gas_price: U256,
+}
+
+impl TransactionRequest {
+    /// Converts the request into the message its sender signs. The kind of
+    /// transaction is the one `validate` returns, missing fields are taken
+    /// from `defaults` and missing data is empty. Fails if the request is
+    /// invalid, for another chain, or an EIP-4844 transaction, which the
+    /// `ethereum` crate does not support.
+    pub fn into_transaction_message(
+        self,
+        chain_id: u64,
+        defaults: FillDefaults,
+    ) -> Result<TransactionMessage, RequestValidationError> {
+        let kind = self.validate()?;
+        self.check_chain_id(U64::from(chain_id))?;
+
+        let nonce = self.nonce.unwrap_or(defaults.nonce);
+        let gas_limit = self.gas.unwrap_or(defaults.gas_limit);
+        let gas_price = self.gas_price.unwrap_or(defaults.gas_price);
+        let action = match self.to {
+            Some(to) => TransactionAction::Call(to),
+            None => TransactionAction::Create,
+        };
+        let value = self.value.unwrap_or_default();
+        let input = self.data.map(|data| data.into_vec()).unwrap_or_default();
+        let access_list = self
+            .access_list
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| EthereumAccessListItem {
+                address: item.address,
+                storage_keys: item.storage_keys,
+            })
+            .collect();
+
+        Ok(match kind {
+            TransactionKind::Legacy => TransactionMessage::Legacy(LegacyTransactionMessage {
+                nonce,
+                gas_price,
+                gas_limit,
+                action,
+                value,
+                input,
+                chain_id: Some(chain_id),
+            }),
+            TransactionKind::Eip2930 => TransactionMessage::EIP2930(EIP2930TransactionMessage {
+                chain_id,
+                nonce,
+                gas_price,
+                gas_limit,
+                action,
+                value,
+                input,
+                access_list,
+            }),
+            TransactionKind::Eip1559 => TransactionMessage::EIP1559(EIP1559TransactionMessage {
+                chain_id,
+                nonce,
+                // Both are set once validated.
+                max_priority_fee_per_gas: self.max_priority_fee_per_gas.unwrap_or_default(),
+                max_fee_per_gas: self.max_fee_per_gas.unwrap_or_default(),
+                gas_limit,
+                action,
+                value,
+                input,
+                access_list,
+            }),
+            TransactionKind::Eip4844 => {
+                return Err(RequestValidationError::UnsupportedType(U64::from(3)))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AccessListItem, Bytes};
+    use ethereum_types::H160;
+    use std::str::FromStr;
+
+    fn transfer() -> TransactionRequest {
+        TransactionRequest {
+            to: Some(H160::repeat_byte(0x35)),
+            gas: Some(U256::from(21000)),
+            value: Some(U256::from(10).pow(U256::from(18))),
+            nonce: Some(U256::from(9)),
+            ..Default::default()
+        }
+    }
+
+    fn access_list() -> Option<Vec<AccessListItem>> {
+        Some(vec![AccessListItem {
+            address: H160::repeat_byte(0x35),
+            storage_keys: vec![H256::from_low_u64_be(1)],
+        }])
+    }
+
+    #[test]
+    fn legacy_transaction_message() {
+        // The example of EIP-155.
+        let request = TransactionRequest {
+            gas_price: Some(U256::from(20_000_000_000u64)),
+            ..transfer()
+        };
+        let message = request
+            .into_transaction_message(1, FillDefaults::default())
+            .unwrap();
+        assert!(matches!(message, TransactionMessage::Legacy(_)));
+        assert_eq!(
+            message.hash(),
+            H256::from_str("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn legacy_transaction_message_defaults() {
+        let request = TransactionRequest {
+            data: Some(Bytes::new(vec![0x60, 0x80])),
+            ..Default::default()
+        };
+        let defaults = FillDefaults {
+            nonce: U256::zero(),
+            gas_limit: U256::from(53000),
+            gas_price: U256::from(1_000_000_000),
+        };
+        let message = request.into_transaction_message(2152, defaults).unwrap();
+        assert_eq!(
+            message,
+            TransactionMessage::Legacy(LegacyTransactionMessage {
+                nonce: U256::zero(),
+                gas_price: U256::from(1_000_000_000),
+                gas_limit: U256::from(53000),
+                action: TransactionAction::Create,
+                value: U256::zero(),
+                input: vec![0x60, 0x80],
+                chain_id: Some(2152),
+            })
+        );
+        assert_eq!(
+            message.hash(),
+            H256::from_str("91ba2191fb8ca3935146a325bfd8da5851f41ad3743450844edad87a30be7f85")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn eip2930_transaction_message() {
+        let request = TransactionRequest {
+            gas_price: Some(U256::from(20_000_000_000u64)),
+            access_list: access_list(),
+            ..transfer()
+        };
+        let message = request
+            .into_transaction_message(1, FillDefaults::default())
+            .unwrap();
+        assert!(matches!(message, TransactionMessage::EIP2930(_)));
+        assert_eq!(
+            message.hash(),
+            H256::from_str("a4441968a0519a1bb79f9a4c352397415c411f98ff1e3bead9dea1f8813aaa37")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn eip1559_transaction_message() {
+        let request = TransactionRequest {
+            max_fee_per_gas: Some(U256::from(30_000_000_000u64)),
+            max_priority_fee_per_gas: Some(U256::from(2_000_000_000)),
+            data: Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb])),
+            access_list: access_list(),
+            ..transfer()
+        };
+        let message = request
+            .into_transaction_message(1, FillDefaults::default())
+            .unwrap();
+        assert!(matches!(message, TransactionMessage::EIP1559(_)));
+        assert_eq!(
+            message.hash(),
+            H256::from_str("22772e6eeb617370e472649d82dc22cd5804b987b47784e6b02c5e1d165180ca")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn transaction_message_errors() {
+        let request = TransactionRequest {
+            chain_id: Some(U64::from(1)),
+            ..transfer()
+        };
+        assert_eq!(
+            request.into_transaction_message(2152, FillDefaults::default()),
+            Err(RequestValidationError::ChainIdMismatch {
+                expected: U64::from(2152),
+                got: U64::from(1),
+            })
+        );
+        let request = TransactionRequest {
+            gas_price: Some(U256::one()),
+            max_fee_per_gas: Some(U256::one()),
+            ..transfer()
+        };
+        assert_eq!(
+            request.into_transaction_message(1, FillDefaults::default()),
+            Err(RequestValidationError::GasPriceWithMaxFee)
+        );
+        let request = TransactionRequest {
+            max_fee_per_gas: Some(U256::one()),
+            max_priority_fee_per_gas: Some(U256::one()),
+            max_fee_per_blob_gas: Some(U256::one()),
+            blob_versioned_hashes: Some(vec![H256::repeat_byte(0x01)]),
+            ..transfer()
+        };
+        assert_eq!(
+            request.into_transaction_message(1, FillDefaults::default()),
+            Err(RequestValidationError::UnsupportedType(U64::from(3)))
+        );
+    }
+}
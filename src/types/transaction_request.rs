@@ -18,13 +18,17 @@
 
 //! `TransactionRequest` type
 
-use crate::types::Bytes;
-use ethereum_types::{H160, U256};
+use super::call_request::merge_input;
+use super::quantity::{deserialize_optional_u256_flexible, deserialize_optional_u64_quantity};
+use crate::types::{AccessList, Bytes, CallRequest};
+use ethereum_types::{H160, H256, U256, U64};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-/// Transaction request coming from RPC
+/// Transaction request coming from RPC. The call data is read from either
+/// `data` or `input`.
 #[derive(Debug, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(try_from = "RawTransactionRequest")]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionRequest {
     /// Sender
@@ -33,6 +37,10 @@ pub struct TransactionRequest {
     pub to: Option<H160>,
     /// Gas Price
     pub gas_price: Option<U256>,
+    /// Max fee per gas
+    pub max_fee_per_gas: Option<U256>,
+    /// Max priority fee per gas
+    pub max_priority_fee_per_gas: Option<U256>,
     /// Gas
     pub gas: Option<U256>,
     /// Value of transaction in wei
@@ -41,4 +49,1127 @@ pub struct TransactionRequest {
     pub data: Option<Bytes>,
     /// Transaction's nonce
     pub nonce: Option<U256>,
+    /// Access list
+    pub access_list: Option<AccessList>,
+    /// Chain id the transaction is meant for
+    pub chain_id: Option<U64>,
+    /// Transaction type
+    #[serde(rename = "type")]
+    pub transaction_type: Option<U64>,
+    /// Max fee per blob gas
+    pub max_fee_per_blob_gas: Option<U256>,
+    /// Versioned hashes of the blobs
+    pub blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+struct RawTransactionRequest {
+    from: Option<H160>,
+    to: Option<H160>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    gas_price: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_fee_per_gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_priority_fee_per_gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    gas: Option<U256>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    value: Option<U256>,
+    data: Option<Bytes>,
+    input: Option<Bytes>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    nonce: Option<U256>,
+    access_list: Option<AccessList>,
+    #[serde(default, deserialize_with = "deserialize_optional_u64_quantity")]
+    chain_id: Option<U64>,
+    #[serde(rename = "type")]
+    transaction_type: Option<U64>,
+    #[serde(default, deserialize_with = "deserialize_optional_u256_flexible")]
+    max_fee_per_blob_gas: Option<U256>,
+    blob_versioned_hashes: Option<Vec<H256>>,
+}
+
+impl TryFrom<RawTransactionRequest> for TransactionRequest {
+    type Error = String;
+
+    fn try_from(raw: RawTransactionRequest) -> Result<TransactionRequest, String> {
+        Ok(TransactionRequest {
+            from: raw.from,
+            to: raw.to,
+            gas_price: raw.gas_price,
+            max_fee_per_gas: raw.max_fee_per_gas,
+            max_priority_fee_per_gas: raw.max_priority_fee_per_gas,
+            gas: raw.gas,
+            value: raw.value,
+            data: merge_input(raw.data, raw.input)?,
+            nonce: raw.nonce,
+            access_list: raw.access_list,
+            chain_id: raw.chain_id,
+            transaction_type: raw.transaction_type,
+            max_fee_per_blob_gas: raw.max_fee_per_blob_gas,
+            blob_versioned_hashes: raw.blob_versioned_hashes,
+        })
+    }
+}
+
+/// How the fee of a transaction request is specified.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FeeMode {
+    /// `gasPrice` only.
+    Legacy,
+    /// `maxFeePerGas` and/or `maxPriorityFeePerGas`.
+    Eip1559,
+    /// No fee field; the node picks the fee.
+    Unspecified,
+}
+
+/// Kind of transaction a request describes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionKind {
+    /// Legacy transaction, paying `gasPrice`.
+    Legacy,
+    /// EIP-2930 transaction, paying `gasPrice` with an access list.
+    Eip2930,
+    /// EIP-1559 transaction, paying `maxFeePerGas` and
+    /// `maxPriorityFeePerGas`.
+    Eip1559,
+    /// EIP-4844 transaction, an EIP-1559 one carrying blobs.
+    Eip4844,
+}
+
+impl fmt::Display for TransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransactionKind::Legacy => write!(f, "legacy"),
+            TransactionKind::Eip2930 => write!(f, "EIP-2930"),
+            TransactionKind::Eip1559 => write!(f, "EIP-1559"),
+            TransactionKind::Eip4844 => write!(f, "EIP-4844"),
+        }
+    }
+}
+
+/// Reason a transaction request is rejected.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RequestValidationError {
+    /// The request is for another chain.
+    ChainIdMismatch {
+        /// Chain id of the node
+        expected: U64,
+        /// Chain id of the request
+        got: U64,
+    },
+    /// Both `gasPrice` and an EIP-1559 fee field are given.
+    GasPriceWithMaxFee,
+    /// `maxPriorityFeePerGas` is greater than `maxFeePerGas`.
+    PriorityFeeAboveMaxFee {
+        /// Max fee per gas
+        max_fee_per_gas: U256,
+        /// Max priority fee per gas
+        max_priority_fee_per_gas: U256,
+    },
+    /// The request has no sender.
+    MissingSender,
+    /// The transaction type is not known.
+    UnsupportedType(U64),
+    /// A field is not allowed for the explicit transaction type.
+    TypeConflict {
+        /// Kind of the transaction
+        kind: TransactionKind,
+        /// Name of the conflicting field
+        field: &'static str,
+    },
+    /// An EIP-4844 transaction has no blob.
+    EmptyBlobVersionedHashes,
+    /// A field required by the kind of transaction is missing.
+    MissingField {
+        /// Kind of the transaction
+        kind: TransactionKind,
+        /// Name of the missing field
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for RequestValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestValidationError::ChainIdMismatch { expected, got } => write!(
+                f,
+                "invalid chain id {} for chain {}",
+                got.as_u64(),
+                expected.as_u64()
+            ),
+            RequestValidationError::GasPriceWithMaxFee => write!(
+                f,
+                "both gasPrice and (maxFeePerGas or maxPriorityFeePerGas) specified"
+            ),
+            RequestValidationError::PriorityFeeAboveMaxFee {
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            } => write!(
+                f,
+                "maxFeePerGas ({}) < maxPriorityFeePerGas ({})",
+                max_fee_per_gas, max_priority_fee_per_gas
+            ),
+            RequestValidationError::MissingSender => write!(f, "missing from"),
+            RequestValidationError::UnsupportedType(transaction_type) => {
+                write!(f, "unsupported transaction type {:#x}", transaction_type)
+            }
+            RequestValidationError::TypeConflict { kind, field } => {
+                write!(f, "{} not allowed for {} transaction", field, kind)
+            }
+            RequestValidationError::EmptyBlobVersionedHashes => {
+                write!(f, "blobVersionedHashes must not be empty")
+            }
+            RequestValidationError::MissingField { kind, field } => {
+                write!(f, "missing {} for {} transaction", field, kind)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RequestValidationError {}
+
+impl TransactionRequest {
+    /// Classifies the request, checking that its fee fields are consistent.
+    /// Without an explicit type, the request is an EIP-4844 transaction if
+    /// it has a blob field, an EIP-1559 one if it has an EIP-1559 fee field,
+    /// an EIP-2930 one if it has an access list and a legacy one otherwise.
+    /// An explicit type must agree with the fields. EIP-1559 and EIP-4844
+    /// transactions need both of the EIP-1559 fee fields, EIP-4844 ones
+    /// also need a recipient, `maxFeePerBlobGas` and at least one blob, and
+    /// EIP-2930 ones an access list; a missing `gasPrice` is left for the
+    /// node to fill.
+    pub fn validate(&self) -> Result<TransactionKind, RequestValidationError> {
+        let has_eip1559_fees =
+            self.max_fee_per_gas.is_some() || self.max_priority_fee_per_gas.is_some();
+        let has_blob_fields =
+            self.max_fee_per_blob_gas.is_some() || self.blob_versioned_hashes.is_some();
+        let kind = match self.transaction_type {
+            None if has_blob_fields => TransactionKind::Eip4844,
+            None if has_eip1559_fees => TransactionKind::Eip1559,
+            None if self.access_list.is_some() => TransactionKind::Eip2930,
+            None => TransactionKind::Legacy,
+            Some(t) if t == U64::from(0) => TransactionKind::Legacy,
+            Some(t) if t == U64::from(1) => TransactionKind::Eip2930,
+            Some(t) if t == U64::from(2) => TransactionKind::Eip1559,
+            Some(t) if t == U64::from(3) => TransactionKind::Eip4844,
+            Some(t) => return Err(RequestValidationError::UnsupportedType(t)),
+        };
+        let conflict = |field| RequestValidationError::TypeConflict { kind, field };
+        let missing = |field| RequestValidationError::MissingField { kind, field };
+        if kind != TransactionKind::Eip4844 {
+            if self.max_fee_per_blob_gas.is_some() {
+                return Err(conflict("maxFeePerBlobGas"));
+            }
+            if self.blob_versioned_hashes.is_some() {
+                return Err(conflict("blobVersionedHashes"));
+            }
+        }
+        match kind {
+            TransactionKind::Legacy | TransactionKind::Eip2930 => {
+                if self.max_fee_per_gas.is_some() {
+                    return Err(conflict("maxFeePerGas"));
+                }
+                if self.max_priority_fee_per_gas.is_some() {
+                    return Err(conflict("maxPriorityFeePerGas"));
+                }
+                match (kind, &self.access_list) {
+                    (TransactionKind::Legacy, Some(_)) => Err(conflict("accessList")),
+                    (TransactionKind::Eip2930, None) => Err(missing("accessList")),
+                    _ => Ok(kind),
+                }
+            }
+            TransactionKind::Eip1559 | TransactionKind::Eip4844 => {
+                if self.gas_price.is_some() {
+                    return Err(if has_eip1559_fees {
+                        RequestValidationError::GasPriceWithMaxFee
+                    } else {
+                        conflict("gasPrice")
+                    });
+                }
+                match (self.max_fee_per_gas, self.max_priority_fee_per_gas) {
+                    (None, _) => return Err(missing("maxFeePerGas")),
+                    (_, None) => return Err(missing("maxPriorityFeePerGas")),
+                    (Some(max_fee_per_gas), Some(max_priority_fee_per_gas))
+                        if max_priority_fee_per_gas > max_fee_per_gas =>
+                    {
+                        return Err(RequestValidationError::PriorityFeeAboveMaxFee {
+                            max_fee_per_gas,
+                            max_priority_fee_per_gas,
+                        });
+                    }
+                    _ => {}
+                }
+                if kind == TransactionKind::Eip4844 {
+                    if self.to.is_none() {
+                        return Err(missing("to"));
+                    }
+                    if self.max_fee_per_blob_gas.is_none() {
+                        return Err(missing("maxFeePerBlobGas"));
+                    }
+                    match &self.blob_versioned_hashes {
+                        None => return Err(missing("blobVersionedHashes")),
+                        Some(hashes) if hashes.is_empty() => {
+                            return Err(RequestValidationError::EmptyBlobVersionedHashes)
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(kind)
+            }
+        }
+    }
+
+    /// Checks the chain id of the request, if any, against the chain id of
+    /// the node.
+    pub fn check_chain_id(&self, chain_id: U64) -> Result<(), RequestValidationError> {
+        match self.chain_id {
+            Some(got) if got != chain_id => Err(RequestValidationError::ChainIdMismatch {
+                expected: chain_id,
+                got,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the fee mode of the request. The EIP-1559 fields take
+    /// precedence when `gasPrice` is given too.
+    pub fn fee_mode(&self) -> FeeMode {
+        if self.max_fee_per_gas.is_some() || self.max_priority_fee_per_gas.is_some() {
+            FeeMode::Eip1559
+        } else if self.gas_price.is_some() {
+            FeeMode::Legacy
+        } else {
+            FeeMode::Unspecified
+        }
+    }
+}
+
+impl From<TransactionRequest> for CallRequest {
+    fn from(request: TransactionRequest) -> CallRequest {
+        let TransactionRequest {
+            from,
+            to,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas,
+            value,
+            data,
+            nonce,
+            access_list,
+            chain_id,
+            transaction_type,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+        } = request;
+        CallRequest {
+            from,
+            to,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas,
+            value,
+            data,
+            nonce,
+            access_list,
+            chain_id,
+            transaction_type,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+        }
+    }
+}
+
+/// Fails if the call has no sender, since it cannot be sent then.
+impl TryFrom<CallRequest> for TransactionRequest {
+    type Error = RequestValidationError;
+
+    fn try_from(request: CallRequest) -> Result<TransactionRequest, RequestValidationError> {
+        let CallRequest {
+            from,
+            to,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas,
+            value,
+            data,
+            nonce,
+            access_list,
+            chain_id,
+            transaction_type,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+        } = request;
+        if from.is_none() {
+            return Err(RequestValidationError::MissingSender);
+        }
+        Ok(TransactionRequest {
+            from,
+            to,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas,
+            value,
+            data,
+            nonce,
+            access_list,
+            chain_id,
+            transaction_type,
+            max_fee_per_blob_gas,
+            blob_versioned_hashes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::H256;
+    use std::str::FromStr;
+
+    #[test]
+    fn transaction_request_eip1559_deserialize() {
+        // As sent by MetaMask.
+        let s = r#"{
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "value": "0xde0b6b3a7640000",
+            "gas": "0x5208",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00"
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized,
+            TransactionRequest {
+                from: Some(H160::from_str("9b2055d370f73ec7d8a03e965129118dc8f5bf83").unwrap()),
+                to: Some(H160::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap()),
+                gas_price: None,
+                max_fee_per_gas: Some(U256::from(10_000_000_000u64)),
+                max_priority_fee_per_gas: Some(U256::from(1_000_000_000)),
+                gas: Some(U256::from(21000)),
+                value: Some(U256::from(10).pow(U256::from(18))),
+                data: None,
+                nonce: None,
+                access_list: None,
+                chain_id: None,
+                transaction_type: None,
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            }
+        );
+        assert_eq!(deserialized.fee_mode(), FeeMode::Eip1559);
+    }
+
+    #[test]
+    fn transaction_request_legacy_deserialize() {
+        let s = r#"{
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "gasPrice": "0x9184e72a000",
+            "gas": "0x76c0",
+            "value": "0x9184e72a",
+            "data": "0xa9059cbb",
+            "nonce": "0x1"
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized,
+            TransactionRequest {
+                from: Some(H160::from_str("9b2055d370f73ec7d8a03e965129118dc8f5bf83").unwrap()),
+                to: Some(H160::from_str("d46e8dd67c5d32be8058bb8eb970870f07244567").unwrap()),
+                gas_price: Some(U256::from(10_000_000_000_000u64)),
+                max_fee_per_gas: None,
+                max_priority_fee_per_gas: None,
+                gas: Some(U256::from(30400)),
+                value: Some(U256::from(2_441_406_250u64)),
+                data: Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb])),
+                nonce: Some(U256::one()),
+                access_list: None,
+                chain_id: None,
+                transaction_type: None,
+                max_fee_per_blob_gas: None,
+                blob_versioned_hashes: None,
+            }
+        );
+        assert_eq!(deserialized.fee_mode(), FeeMode::Legacy);
+    }
+
+    #[test]
+    fn transaction_request_fee_mode() {
+        assert_eq!(
+            TransactionRequest::default().fee_mode(),
+            FeeMode::Unspecified
+        );
+        let request = TransactionRequest {
+            gas_price: Some(U256::one()),
+            max_priority_fee_per_gas: Some(U256::one()),
+            ..Default::default()
+        };
+        assert_eq!(request.fee_mode(), FeeMode::Eip1559);
+    }
+
+    #[test]
+    fn transaction_request_access_list_serde() {
+        let s = r#"{"from":"0x9b2055d370f73ec7d8a03e965129118dc8f5bf83","to":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","gasPrice":"0x3b9aca00","maxFeePerGas":null,"maxPriorityFeePerGas":null,"gas":"0x7530","value":null,"data":"0xa9059cbb","nonce":"0x0","accessList":[{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081","0x0000000000000000000000000000000000000000000000000000000000000003"]},{"address":"0xd46e8dd67c5d32be8058bb8eb970870f07244567","storageKeys":[]}]}"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        let access_list = deserialized.access_list.as_ref().unwrap();
+        assert_eq!(access_list.len(), 2);
+        assert_eq!(
+            access_list[0].storage_keys,
+            vec![H256::from_low_u64_be(0x81), H256::from_low_u64_be(3)]
+        );
+        let serialized = serde_json::to_string(&deserialized).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransactionRequest>(&serialized).unwrap(),
+            deserialized
+        );
+    }
+
+    #[test]
+    fn transaction_request_viem_deserialize() {
+        // As sent by viem's sendTransaction.
+        let s = r#"{
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "gas": "0xb411",
+            "input": "0xa9059cbb000000000000000000000000d46e8dd67c5d32be8058bb8eb970870f07244567000000000000000000000000000000000000000000000000000000000000002a",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "nonce": "0x7",
+            "to": "0xa02457e5dfd32bda5fc7e1f1b008aa5979568150",
+            "value": "0x0"
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        let data = deserialized.data.unwrap();
+        assert_eq!(data.0.len(), 68);
+        assert_eq!(data.0[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+        assert_eq!(data.0[67], 0x2a);
+    }
+
+    #[test]
+    fn transaction_request_input_deserialize() {
+        let calldata = Some(Bytes::new(vec![0xa9, 0x05, 0x9c, 0xbb]));
+        for s in [
+            r#"{"data":"0xa9059cbb"}"#,
+            r#"{"input":"0xa9059cbb"}"#,
+            r#"{"data":"0xa9059cbb","input":"0xa9059cbb"}"#,
+        ] {
+            let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+            assert_eq!(deserialized.data, calldata);
+        }
+        assert!(serde_json::from_str::<TransactionRequest>(
+            r#"{"data":"0xa9059cbb","input":"0x"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn transaction_request_chain_id_deserialize() {
+        for s in [
+            r#"{"chainId":"0x868"}"#,
+            r#"{"chainId":2152}"#,
+            r#"{"chainId":"2152"}"#,
+        ] {
+            let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+            assert_eq!(deserialized.chain_id, Some(U64::from(2152)));
+        }
+        let deserialized: TransactionRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(deserialized.chain_id, None);
+        assert!(
+            serde_json::from_str::<TransactionRequest>(r#"{"chainId":"0x10000000000000000"}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn transaction_request_check_chain_id() {
+        let chain_id = U64::from(2152);
+        assert_eq!(
+            TransactionRequest::default().check_chain_id(chain_id),
+            Ok(())
+        );
+        let request = TransactionRequest {
+            chain_id: Some(U64::from(2152)),
+            ..Default::default()
+        };
+        assert_eq!(request.check_chain_id(chain_id), Ok(()));
+        let request = TransactionRequest {
+            chain_id: Some(U64::from(1)),
+            ..Default::default()
+        };
+        let err = request.check_chain_id(chain_id).unwrap_err();
+        assert_eq!(
+            err,
+            RequestValidationError::ChainIdMismatch {
+                expected: chain_id,
+                got: U64::from(1),
+            }
+        );
+        assert_eq!(err.to_string(), "invalid chain id 1 for chain 2152");
+    }
+
+    #[test]
+    fn transaction_request_validate() {
+        use RequestValidationError::*;
+
+        let missing = |field| MissingField {
+            kind: TransactionKind::Eip1559,
+            field,
+        };
+        let above = PriorityFeeAboveMaxFee {
+            max_fee_per_gas: U256::from(1),
+            max_priority_fee_per_gas: U256::from(2),
+        };
+        // (gasPrice, maxFeePerGas, maxPriorityFeePerGas) and the result
+        // without and with an access list.
+        let cases = vec![
+            (
+                None,
+                None,
+                None,
+                Ok(TransactionKind::Legacy),
+                Ok(TransactionKind::Eip2930),
+            ),
+            (
+                Some(1),
+                None,
+                None,
+                Ok(TransactionKind::Legacy),
+                Ok(TransactionKind::Eip2930),
+            ),
+            (
+                Some(1),
+                Some(1),
+                None,
+                Err(GasPriceWithMaxFee),
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                Some(1),
+                Some(3),
+                None,
+                Err(GasPriceWithMaxFee),
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                Some(1),
+                None,
+                Some(2),
+                Err(GasPriceWithMaxFee),
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                Some(1),
+                Some(1),
+                Some(2),
+                Err(GasPriceWithMaxFee),
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                Some(1),
+                Some(3),
+                Some(2),
+                Err(GasPriceWithMaxFee),
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                None,
+                Some(1),
+                None,
+                Err(missing("maxPriorityFeePerGas")),
+                Err(missing("maxPriorityFeePerGas")),
+            ),
+            (
+                None,
+                Some(3),
+                None,
+                Err(missing("maxPriorityFeePerGas")),
+                Err(missing("maxPriorityFeePerGas")),
+            ),
+            (
+                None,
+                None,
+                Some(2),
+                Err(missing("maxFeePerGas")),
+                Err(missing("maxFeePerGas")),
+            ),
+            (
+                None,
+                Some(1),
+                Some(2),
+                Err(above.clone()),
+                Err(above.clone()),
+            ),
+            (
+                None,
+                Some(3),
+                Some(2),
+                Ok(TransactionKind::Eip1559),
+                Ok(TransactionKind::Eip1559),
+            ),
+        ];
+        for (gas_price, max_fee_per_gas, max_priority_fee_per_gas, without, with) in cases {
+            let mut request = TransactionRequest {
+                gas_price: gas_price.map(U256::from),
+                max_fee_per_gas: max_fee_per_gas.map(U256::from),
+                max_priority_fee_per_gas: max_priority_fee_per_gas.map(U256::from),
+                ..Default::default()
+            };
+            assert_eq!(request.validate(), without, "{:?}", request);
+            request.access_list = Some(vec![]);
+            assert_eq!(request.validate(), with, "{:?}", request);
+        }
+    }
+
+    #[test]
+    fn transaction_request_typed_deserialize() {
+        let s = r#"{
+            "type": "0x1",
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "gasPrice": "0x3b9aca00",
+            "accessList": [{"address":"0xa02457e5dfd32bda5fc7e1f1b008aa5979568150","storageKeys":["0x0000000000000000000000000000000000000000000000000000000000000081"]}]
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(1)));
+        assert_eq!(deserialized.validate(), Ok(TransactionKind::Eip2930));
+
+        let s = r#"{
+            "type": "0x2",
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00"
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(2)));
+        assert_eq!(deserialized.validate(), Ok(TransactionKind::Eip1559));
+
+        let deserialized: TransactionRequest = serde_json::from_str(r#"{"type":"0x7e"}"#).unwrap();
+        assert_eq!(
+            deserialized.validate(),
+            Err(RequestValidationError::UnsupportedType(U64::from(0x7e)))
+        );
+    }
+
+    #[test]
+    fn transaction_request_validate_typed() {
+        use RequestValidationError::*;
+        use TransactionKind::*;
+
+        let fee = Some(U256::from(2));
+        let access_list = Some(vec![]);
+        let request = |transaction_type: u64| TransactionRequest {
+            transaction_type: Some(U64::from(transaction_type)),
+            ..Default::default()
+        };
+        let cases = vec![
+            (request(0), Ok(Legacy)),
+            (
+                TransactionRequest {
+                    gas_price: fee,
+                    ..request(0)
+                },
+                Ok(Legacy),
+            ),
+            (
+                TransactionRequest {
+                    max_fee_per_gas: fee,
+                    ..request(0)
+                },
+                Err(TypeConflict {
+                    kind: Legacy,
+                    field: "maxFeePerGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    max_priority_fee_per_gas: fee,
+                    ..request(0)
+                },
+                Err(TypeConflict {
+                    kind: Legacy,
+                    field: "maxPriorityFeePerGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    access_list: access_list.clone(),
+                    ..request(0)
+                },
+                Err(TypeConflict {
+                    kind: Legacy,
+                    field: "accessList",
+                }),
+            ),
+            (
+                request(1),
+                Err(MissingField {
+                    kind: Eip2930,
+                    field: "accessList",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    access_list: access_list.clone(),
+                    ..request(1)
+                },
+                Ok(Eip2930),
+            ),
+            (
+                TransactionRequest {
+                    access_list: access_list.clone(),
+                    max_fee_per_gas: fee,
+                    max_priority_fee_per_gas: fee,
+                    ..request(1)
+                },
+                Err(TypeConflict {
+                    kind: Eip2930,
+                    field: "maxFeePerGas",
+                }),
+            ),
+            (
+                request(2),
+                Err(MissingField {
+                    kind: Eip1559,
+                    field: "maxFeePerGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    gas_price: fee,
+                    ..request(2)
+                },
+                Err(TypeConflict {
+                    kind: Eip1559,
+                    field: "gasPrice",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    gas_price: fee,
+                    max_fee_per_gas: fee,
+                    max_priority_fee_per_gas: fee,
+                    ..request(2)
+                },
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                TransactionRequest {
+                    max_fee_per_gas: fee,
+                    max_priority_fee_per_gas: fee,
+                    access_list,
+                    ..request(2)
+                },
+                Ok(Eip1559),
+            ),
+            (request(4), Err(UnsupportedType(U64::from(4)))),
+        ];
+        for (request, expected) in cases {
+            assert_eq!(request.validate(), expected, "{:?}", request);
+        }
+    }
+
+    #[test]
+    fn transaction_request_eip4844_deserialize() {
+        // As generated by viem for a type-3 request, the blob sidecar left out.
+        let s = r#"{
+            "type": "0x3",
+            "from": "0x9b2055d370f73ec7d8a03e965129118dc8f5bf83",
+            "to": "0xd46e8dd67c5d32be8058bb8eb970870f07244567",
+            "gas": "0x5208",
+            "nonce": "0x2",
+            "value": "0x0",
+            "maxFeePerGas": "0x2540be400",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "maxFeePerBlobGas": "0x77359400",
+            "blobVersionedHashes": ["0x01a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8"]
+        }"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.transaction_type, Some(U64::from(3)));
+        assert_eq!(
+            deserialized.max_fee_per_blob_gas,
+            Some(U256::from(2_000_000_000))
+        );
+        assert_eq!(
+            deserialized.blob_versioned_hashes,
+            Some(vec![H256::from_str(
+                "01a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8"
+            )
+            .unwrap()])
+        );
+        assert_eq!(deserialized.validate(), Ok(TransactionKind::Eip4844));
+
+        // Without the explicit type too.
+        let untyped = TransactionRequest {
+            transaction_type: None,
+            ..deserialized.clone()
+        };
+        assert_eq!(untyped.validate(), Ok(TransactionKind::Eip4844));
+    }
+
+    #[test]
+    fn transaction_request_validate_eip4844() {
+        use RequestValidationError::*;
+        use TransactionKind::*;
+
+        let blob = TransactionRequest {
+            to: Some(H160::repeat_byte(0x01)),
+            max_fee_per_gas: Some(U256::from(2)),
+            max_priority_fee_per_gas: Some(U256::from(1)),
+            max_fee_per_blob_gas: Some(U256::from(1)),
+            blob_versioned_hashes: Some(vec![H256::repeat_byte(0x01)]),
+            ..Default::default()
+        };
+        let cases = vec![
+            (blob.clone(), Ok(Eip4844)),
+            (
+                TransactionRequest {
+                    to: None,
+                    ..blob.clone()
+                },
+                Err(MissingField {
+                    kind: Eip4844,
+                    field: "to",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    max_fee_per_blob_gas: None,
+                    ..blob.clone()
+                },
+                Err(MissingField {
+                    kind: Eip4844,
+                    field: "maxFeePerBlobGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    blob_versioned_hashes: None,
+                    ..blob.clone()
+                },
+                Err(MissingField {
+                    kind: Eip4844,
+                    field: "blobVersionedHashes",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    blob_versioned_hashes: Some(vec![]),
+                    ..blob.clone()
+                },
+                Err(EmptyBlobVersionedHashes),
+            ),
+            (
+                TransactionRequest {
+                    max_priority_fee_per_gas: None,
+                    ..blob.clone()
+                },
+                Err(MissingField {
+                    kind: Eip4844,
+                    field: "maxPriorityFeePerGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    gas_price: Some(U256::from(1)),
+                    ..blob.clone()
+                },
+                Err(GasPriceWithMaxFee),
+            ),
+            (
+                TransactionRequest {
+                    transaction_type: Some(U64::from(2)),
+                    ..blob.clone()
+                },
+                Err(TypeConflict {
+                    kind: Eip1559,
+                    field: "maxFeePerBlobGas",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    transaction_type: Some(U64::from(0)),
+                    max_fee_per_gas: None,
+                    max_priority_fee_per_gas: None,
+                    max_fee_per_blob_gas: None,
+                    ..blob.clone()
+                },
+                Err(TypeConflict {
+                    kind: Legacy,
+                    field: "blobVersionedHashes",
+                }),
+            ),
+            (
+                TransactionRequest {
+                    transaction_type: Some(U64::from(3)),
+                    ..Default::default()
+                },
+                Err(MissingField {
+                    kind: Eip4844,
+                    field: "maxFeePerGas",
+                }),
+            ),
+        ];
+        for (request, expected) in cases {
+            assert_eq!(request.validate(), expected, "{:?}", request);
+        }
+    }
+
+    #[test]
+    fn request_validation_error_display() {
+        assert_eq!(
+            RequestValidationError::GasPriceWithMaxFee.to_string(),
+            "both gasPrice and (maxFeePerGas or maxPriorityFeePerGas) specified"
+        );
+        assert_eq!(
+            RequestValidationError::PriorityFeeAboveMaxFee {
+                max_fee_per_gas: U256::from(1),
+                max_priority_fee_per_gas: U256::from(2),
+            }
+            .to_string(),
+            "maxFeePerGas (1) < maxPriorityFeePerGas (2)"
+        );
+        assert_eq!(
+            RequestValidationError::MissingField {
+                kind: TransactionKind::Eip1559,
+                field: "maxFeePerGas",
+            }
+            .to_string(),
+            "missing maxFeePerGas for EIP-1559 transaction"
+        );
+        assert_eq!(
+            RequestValidationError::TypeConflict {
+                kind: TransactionKind::Legacy,
+                field: "maxFeePerGas",
+            }
+            .to_string(),
+            "maxFeePerGas not allowed for legacy transaction"
+        );
+        assert_eq!(
+            RequestValidationError::UnsupportedType(U64::from(0x7e)).to_string(),
+            "unsupported transaction type 0x7e"
+        );
+        assert_eq!(
+            RequestValidationError::EmptyBlobVersionedHashes.to_string(),
+            "blobVersionedHashes must not be empty"
+        );
+    }
+
+    /// Small deterministic generator, so that the round trip tests cover
+    /// every field being set or not without a property testing dependency.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            // xorshift64
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn option<T>(&mut self, f: impl FnOnce(u64) -> T) -> Option<T> {
+            let value = self.next();
+            if value & 1 == 0 {
+                Some(f(value))
+            } else {
+                None
+            }
+        }
+    }
+
+    fn random_request(rng: &mut Rng) -> TransactionRequest {
+        TransactionRequest {
+            from: rng.option(H160::from_low_u64_be),
+            to: rng.option(H160::from_low_u64_be),
+            gas_price: rng.option(U256::from),
+            max_fee_per_gas: rng.option(U256::from),
+            max_priority_fee_per_gas: rng.option(U256::from),
+            gas: rng.option(U256::from),
+            value: rng.option(U256::from),
+            data: rng.option(|value| Bytes::new(value.to_be_bytes().to_vec())),
+            nonce: rng.option(U256::from),
+            access_list: rng.option(|value| {
+                vec![crate::types::AccessListItem {
+                    address: H160::from_low_u64_be(value),
+                    storage_keys: vec![H256::from_low_u64_be(value)],
+                }]
+            }),
+            chain_id: rng.option(U64::from),
+            transaction_type: rng.option(|value| U64::from(value % 4)),
+            max_fee_per_blob_gas: rng.option(U256::from),
+            blob_versioned_hashes: rng.option(|value| vec![H256::from_low_u64_be(value)]),
+        }
+    }
+
+    #[test]
+    fn transaction_request_call_request_round_trip() {
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        for _ in 0..256 {
+            let request = random_request(&mut rng);
+            let call = CallRequest::from(request.clone());
+            match TransactionRequest::try_from(call.clone()) {
+                Ok(back) => {
+                    assert_eq!(back, request);
+                    assert_eq!(CallRequest::from(back), call);
+                }
+                Err(err) => {
+                    assert_eq!(request.from, None);
+                    assert_eq!(err, RequestValidationError::MissingSender);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transaction_request_flexible_quantities() {
+        let fields = [
+            "gas",
+            "gasPrice",
+            "maxFeePerGas",
+            "maxPriorityFeePerGas",
+            "maxFeePerBlobGas",
+            "value",
+            "nonce",
+            "chainId",
+        ];
+        for field in fields {
+            for encoding in [r#""0x1a""#, r#""26""#, "26"] {
+                let s = format!(r#"{{"{}":{}}}"#, field, encoding);
+                let deserialized: TransactionRequest = serde_json::from_str(&s).unwrap();
+                assert_eq!(
+                    serde_json::to_value(&deserialized).unwrap()[field],
+                    "0x1a",
+                    "{}",
+                    s
+                );
+            }
+            let s = format!(r#"{{"{}":"0x"}}"#, field);
+            assert!(
+                serde_json::from_str::<TransactionRequest>(&s).is_err(),
+                "{}",
+                s
+            );
+        }
+
+        // A quantity above u64 sent as a decimal string, and 1 ether sent as
+        // a number.
+        let s = r#"{"value":"100000000000000000000000"}"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            deserialized.value,
+            Some(U256::from_dec_str("100000000000000000000000").unwrap())
+        );
+        let s = r#"{"value":1000000000000000000}"#;
+        let deserialized: TransactionRequest = serde_json::from_str(s).unwrap();
+        assert_eq!(deserialized.value, Some(U256::from(10).pow(U256::from(18))));
+    }
 }
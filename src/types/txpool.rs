@@ -0,0 +1,334 @@
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+// This file is part of Frontier.
+//
+// Copyright (c) 2015-2020 Parity Technologies (UK) Ltd.
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use ethereum_types::{H160, U256};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::Transaction;
+
+/// The result of a `txpool_status` call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxPoolStatus {
+    /// Number of pending transactions
+    pub pending: U256,
+    /// Number of queued transactions
+    pub queued: U256,
+}
+
+/// Pool entries of a single sender, keyed by nonce.
+///
+/// Nonces are encoded as decimal strings, as geth does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPoolEntry<T>(pub BTreeMap<U256, T>);
+
+impl<T> Default for TxPoolEntry<T> {
+    fn default() -> Self {
+        TxPoolEntry(BTreeMap::new())
+    }
+}
+
+impl<T: Serialize> Serialize for TxPoolEntry<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.0.iter().map(|(nonce, v)| (nonce.to_string(), v)))
+    }
+}
+
+impl<'a, T: DeserializeOwned> Deserialize<'a> for TxPoolEntry<T> {
+    fn deserialize<D>(deserializer: D) -> Result<TxPoolEntry<T>, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let map: BTreeMap<String, T> = Deserialize::deserialize(deserializer)?;
+        map.into_iter()
+            .map(|(nonce, v)| {
+                U256::from_dec_str(&nonce)
+                    .map(|nonce| (nonce, v))
+                    .map_err(|e| D::Error::custom(format!("Invalid nonce {}: {:?}", nonce, e)))
+            })
+            .collect::<Result<_, _>>()
+            .map(TxPoolEntry)
+    }
+}
+
+/// Pending and queued pool entries of every sender.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
+pub struct TxPoolResult<T> {
+    /// Pending transactions by sender
+    pub pending: BTreeMap<H160, TxPoolEntry<T>>,
+    /// Queued transactions by sender
+    pub queued: BTreeMap<H160, TxPoolEntry<T>>,
+}
+
+impl<T> Default for TxPoolResult<T> {
+    fn default() -> Self {
+        TxPoolResult {
+            pending: BTreeMap::new(),
+            queued: BTreeMap::new(),
+        }
+    }
+}
+
+/// Transactions of a single sender, keyed by nonce.
+pub type TxPoolContentEntry = TxPoolEntry<Transaction>;
+
+/// The result of a `txpool_content` call.
+pub type TxPoolContent = TxPoolResult<Transaction>;
+
+/// The result of a `txpool_contentFrom` call: the pool entries of a single
+/// sender.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxPoolContentFrom {
+    /// Pending transactions by nonce
+    pub pending: TxPoolContentEntry,
+    /// Queued transactions by nonce
+    pub queued: TxPoolContentEntry,
+}
+
+/// Summary of a pool transaction, as returned by `txpool_inspect`.
+///
+/// Encoded as `"<to>: <value> wei + <gas> gas × <gas price> wei"`, or with
+/// `contract creation` in place of the recipient.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TxPoolInspectSummary {
+    /// Recipient, `None` for contract creations
+    pub to: Option<H160>,
+    /// Transfered value
+    pub value: U256,
+    /// Gas
+    pub gas: U256,
+    /// Gas Price
+    pub gas_price: U256,
+}
+
+const CONTRACT_CREATION: &str = "contract creation";
+
+impl fmt::Display for TxPoolInspectSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to {
+            Some(ref to) => write!(f, "{:?}", to)?,
+            None => write!(f, "{}", CONTRACT_CREATION)?,
+        }
+        write!(
+            f,
+            ": {} wei + {} gas × {} wei",
+            self.value, self.gas, self.gas_price
+        )
+    }
+}
+
+impl FromStr for TxPoolInspectSummary {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid txpool summary: {}", s);
+        let quantity = |v: &str| U256::from_dec_str(v).map_err(|_| invalid());
+
+        let (to, rest) = s.split_once(": ").ok_or_else(invalid)?;
+        let to = match to {
+            CONTRACT_CREATION => None,
+            _ => Some(H160::from_str(to).map_err(|_| invalid())?),
+        };
+        let (value, rest) = rest.split_once(" wei + ").ok_or_else(invalid)?;
+        let (gas, rest) = rest.split_once(" gas × ").ok_or_else(invalid)?;
+        let gas_price = rest.strip_suffix(" wei").ok_or_else(invalid)?;
+
+        Ok(TxPoolInspectSummary {
+            to,
+            value: quantity(value)?,
+            gas: quantity(gas)?,
+            gas_price: quantity(gas_price)?,
+        })
+    }
+}
+
+impl Serialize for TxPoolInspectSummary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'a> Deserialize<'a> for TxPoolInspectSummary {
+    fn deserialize<D>(deserializer: D) -> Result<TxPoolInspectSummary, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+/// The result of a `txpool_inspect` call.
+pub type TxPoolInspect = TxPoolResult<TxPoolInspectSummary>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn txpool_status_serde() {
+        let s = r#"{"pending":"0xa","queued":"0x7"}"#;
+        let status: TxPoolStatus = serde_json::from_str(s).unwrap();
+        assert_eq!(
+            status,
+            TxPoolStatus {
+                pending: U256::from(10),
+                queued: U256::from(7),
+            }
+        );
+        assert_eq!(serde_json::to_string(&status).unwrap(), s);
+    }
+
+    #[test]
+    fn txpool_content_serde() {
+        let s = r#"{
+            "pending": {
+                "0x0216d5032f356960cd3749c31ab34eeff21b3395": {
+                    "806": {
+                        "blockHash": null, "blockNumber": null,
+                        "from": "0x0216d5032f356960cd3749c31ab34eeff21b3395",
+                        "gas": "0x5208", "gasPrice": "0xba43b7400",
+                        "hash": "0xaf953a2d01f55cfe080c0c94150a60105e8ac3d51153058a1f03dd239dd08586",
+                        "input": "0x", "nonce": "0x326",
+                        "to": "0x7f69a91a3cf4be60020fb58b893b7cbb65376db8",
+                        "transactionIndex": null, "value": "0x19a99f0cf456000",
+                        "v": "0x1c",
+                        "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+                        "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c"
+                    }
+                }
+            },
+            "queued": {
+                "0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c": {
+                    "2": {
+                        "blockHash": null, "blockNumber": null,
+                        "from": "0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c",
+                        "gas": "0x15f90", "gasPrice": "0x4a817c800",
+                        "hash": "0x57b30c59fc39a50e1cba90e3099286dfa5aaf60294a629240b5bbec6e2e66576",
+                        "input": "0x", "nonce": "0x2",
+                        "to": "0x346fb27de7e7370008f5da379f74dd49f5f2f80f",
+                        "transactionIndex": null, "value": "0x1f161421c8e0000",
+                        "v": "0x1b",
+                        "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+                        "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c"
+                    },
+                    "10": {
+                        "blockHash": null, "blockNumber": null,
+                        "from": "0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c",
+                        "gas": "0x15f90", "gasPrice": "0x4a817c800",
+                        "hash": "0xcb6e68fe2fd2f1ef2df4d2cc4f5e2df4a2850f0e7e6f36b4a05bbe69b8c8c563",
+                        "input": "0x", "nonce": "0xa",
+                        "to": "0x346fb27de7e7370008f5da379f74dd49f5f2f80f",
+                        "transactionIndex": null, "value": "0x1f161421c8e0000",
+                        "v": "0x1b",
+                        "r": "0x1b5e176d927f8e9ab405058b2d2457392da3e20f328b16ddabcebc33eaac5fea",
+                        "s": "0x4ba69724e8f69de52f0125ad8b3c5c2cef33019bac3249e2c0a2192766d1721c"
+                    }
+                }
+            }
+        }"#;
+        let content: TxPoolContent = serde_json::from_str(s).unwrap();
+
+        let sender = H160::from_str("976a3fc5d6f7d259ebfb4cc2ae75115475e9867c").unwrap();
+        let queued = &content.queued[&sender].0;
+        assert_eq!(
+            queued.keys().cloned().collect::<Vec<_>>(),
+            vec![U256::from(2), U256::from(10)]
+        );
+        assert_eq!(queued[&U256::from(10)].nonce, U256::from(10));
+
+        let serialized = serde_json::to_string(&content).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TxPoolContent>(&serialized).unwrap(),
+            content
+        );
+        let value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert!(value["queued"]["0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c"]["10"].is_object());
+    }
+
+    #[test]
+    fn txpool_entry_rejects_hex_nonce() {
+        let entry: Result<TxPoolEntry<String>, _> = serde_json::from_str(r#"{"0x1":"x"}"#);
+        assert!(entry.is_err());
+    }
+
+    #[test]
+    fn txpool_inspect_summary_call() {
+        let s = "0x7f69a91a3cf4be60020fb58b893b7cbb65376db8: 1000000000000000000 wei + 21000 gas × 1000000000 wei";
+        let summary: TxPoolInspectSummary = s.parse().unwrap();
+        assert_eq!(
+            summary,
+            TxPoolInspectSummary {
+                to: Some(H160::from_str("7f69a91a3cf4be60020fb58b893b7cbb65376db8").unwrap()),
+                value: U256::exp10(18),
+                gas: U256::from(21000),
+                gas_price: U256::exp10(9),
+            }
+        );
+        assert_eq!(summary.to_string(), s);
+    }
+
+    #[test]
+    fn txpool_inspect_summary_creation() {
+        let s = "contract creation: 0 wei + 1000000 gas × 1000000000 wei";
+        let summary: TxPoolInspectSummary = s.parse().unwrap();
+        assert_eq!(summary.to, None);
+        assert_eq!(summary.gas, U256::from(1_000_000));
+        assert_eq!(summary.to_string(), s);
+    }
+
+    #[test]
+    fn txpool_inspect_summary_invalid() {
+        assert!("0x7f69: 1 wei + 2 gas × 3 wei"
+            .parse::<TxPoolInspectSummary>()
+            .is_err());
+        assert!("contract creation: 1 wei + 2 gas"
+            .parse::<TxPoolInspectSummary>()
+            .is_err());
+    }
+
+    #[test]
+    fn txpool_inspect_serde() {
+        let s = r#"{"pending":{},"queued":{"0x976a3fc5d6f7d259ebfb4cc2ae75115475e9867c":{"3":"contract creation: 0 wei + 90000 gas × 20000000000 wei"}}}"#;
+        let inspect: TxPoolInspect = serde_json::from_str(s).unwrap();
+        assert_eq!(serde_json::to_string(&inspect).unwrap(), s);
+    }
+
+    #[test]
+    fn txpool_content_from_empty() {
+        let content = TxPoolContentFrom::default();
+        let s = serde_json::to_string(&content).unwrap();
+        assert_eq!(s, r#"{"pending":{},"queued":{}}"#);
+        assert_eq!(
+            serde_json::from_str::<TxPoolContentFrom>(&s).unwrap(),
+            content
+        );
+    }
+}
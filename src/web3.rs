@@ -34,3 +34,31 @@ pub trait Web3Api {
     #[rpc(name = "web3_sha3")]
     fn sha3(&self, _: Bytes) -> Result<H256>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpc_core::IoHandler;
+
+    struct MockWeb3;
+
+    impl Web3Api for MockWeb3 {
+        fn client_version(&self) -> BoxFuture<Result<String>> {
+            Box::pin(futures::future::ready(Ok("mock/v0.1.0".to_string())))
+        }
+
+        fn sha3(&self, input: Bytes) -> Result<H256> {
+            Ok(H256::from_low_u64_be(input.0.len() as u64))
+        }
+    }
+
+    #[test]
+    fn sha3_accepts_empty_input() {
+        let mut io = IoHandler::new();
+        io.extend_with(MockWeb3.to_delegate());
+
+        let request = r#"{"jsonrpc":"2.0","method":"web3_sha3","params":["0x"],"id":1}"#;
+        let response = r#"{"jsonrpc":"2.0","result":"0x0000000000000000000000000000000000000000000000000000000000000000","id":1}"#;
+        assert_eq!(io.handle_request_sync(request), Some(response.to_string()));
+    }
+}